@@ -0,0 +1,115 @@
+use std::fmt::{Display, Formatter};
+use num_bigint::BigInt;
+use crate::grid::Coord;
+
+/// The result of a part. Every integer type converts without a cast: values
+/// which don't fit into `i64` become a `BigInteger`.
+/// Answers are compared by how they are printed, so `Integer(5)` equals `5` and
+/// `Coordinate(6, 1)` equals `"6,1"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    /// Printed as `x,y`.
+    Coordinate(i64, i64),
+    /// The part found no answer for the input, with the reason.
+    NoSolution(String)
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Coordinate(x, y) => write!(f, "{},{}", x, y),
+            Answer::NoSolution(reason) => write!(f, "no solution ({})", reason)
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),+) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Integer(n),
+                    Err(_) => Answer::BigInteger(BigInt::from(n))
+                }
+            }
+        })+
+    };
+}
+
+from_integer!(i32, u32, i64, u64, usize, i128, u128);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i64::try_from(&n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::BigInteger(n)
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(String::from(s))
+    }
+}
+
+impl From<Coord> for Answer {
+    fn from(c: Coord) -> Self {
+        Answer::Coordinate(c.0, c.1)
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self, Answer::Integer(n) if n == other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Integer(n) => other.parse::<i64>() == Ok(*n),
+            Answer::BigInteger(n) => other.parse::<BigInt>().as_ref() == Ok(n),
+            Answer::Text(s) => s == other,
+            Answer::Coordinate(x, y) => match other.split_once(',') {
+                Some((a, b)) => a.parse::<i64>() == Ok(*x) && b.parse::<i64>() == Ok(*y),
+                None => false
+            },
+            Answer::NoSolution(_) => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(3749usize), Answer::Integer(3749));
+        assert_eq!(Answer::from(3749usize), 3749);
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert!(matches!(Answer::from(u64::MAX), Answer::BigInteger(_)));
+
+        assert_eq!(Answer::from((6, 1)), "6,1");
+        assert_eq!(Answer::from("co,de,ka,ta"), "co,de,ka,ta");
+        assert_ne!(Answer::from(12), "13");
+        assert_eq!(Answer::from(u128::MAX), "340282366920938463463374607431768211455");
+
+        let none = Answer::NoSolution(String::from("no path"));
+        assert_eq!(none.to_string(), "no solution (no path)");
+        assert_ne!(none, "no solution (no path)");
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::input::InputError;
+use crate::parse::ParseError;
+
+/// (x, y) position, x is the column and y the row. The origin is the top left corner.
+pub type Coord = (i64, i64);
+
+pub const NEIGHBOURS_4: [Coord; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const NEIGHBOURS_8: [Coord; 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Dense, rectangular 2D map. All accessors taking a `Coord` are bounds-checked,
+/// `row` and `column` panic outside of the grid like indexing does.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Converts each character of the (equally long) lines into a cell.
+    pub fn parse<F: FnMut(char) -> T>(lines: &[String], mut f: F) -> Result<Self, InputError> {
+        if lines.is_empty() {
+            return Err(InputError::Empty);
+        }
+
+        let width = lines[0].chars().count();
+        let mut cells: Vec<T> = Vec::with_capacity(width * lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            let found = line.chars().count();
+            if found != width {
+                return Err(InputError::RaggedGrid { line: index + 1, expected: width, found });
+            }
+            cells.extend(line.chars().map(&mut f));
+        }
+
+        Ok(Grid { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, c: Coord) -> bool {
+        c.0 >= 0 && c.1 >= 0 && (c.0 as usize) < self.width && (c.1 as usize) < self.height
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        self.index_of(c).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        self.index_of(c).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the cell at `c` and returns the old value, `None` if `c` is out of bounds.
+    pub fn set(&mut self, c: Coord, value: T) -> Option<T> {
+        self.get_mut(c).map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| ((i % width) as i64, (i / width) as i64))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(c, &NEIGHBOURS_4)
+    }
+
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(c, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(&'a self, c: Coord, offsets: &'a [Coord]) -> impl Iterator<Item = Coord> + 'a {
+        offsets.iter()
+            .map(move |o| (c.0 + o.0, c.1 + o.1))
+            .filter(|n| self.in_bounds(*n))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is outside of the {}x{} grid", y, self.width, self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the {}x{} grid", x, self.width, self.height);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells starting at `start` (inclusive) going in `step` direction until the grid ends.
+    pub fn ray(&self, start: Coord, step: Coord) -> impl Iterator<Item = &T> {
+        (0..)
+            .map(move |i| (start.0 + i * step.0, start.1 + i * step.1))
+            .map_while(|c| self.get(c))
+    }
+
+    /// Diagonal from top left to bottom right which contains `c`.
+    pub fn diagonal(&self, c: Coord) -> impl Iterator<Item = &T> {
+        let offset = c.0.min(c.1);
+        self.ray((c.0 - offset, c.1 - offset), (1, 1))
+    }
+
+    /// Diagonal from top right to bottom left which contains `c`.
+    pub fn anti_diagonal(&self, c: Coord) -> impl Iterator<Item = &T> {
+        let offset = (self.width as i64 - 1 - c.0).min(c.1);
+        self.ray((c.0 + offset, c.1 - offset), (-1, 1))
+    }
+
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Coord> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(c, _)| c)
+    }
+
+    pub fn find_all<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Vec<Coord> {
+        self.iter().filter(|(_, t)| predicate(t)).map(|(c, _)| c).collect()
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Like `map`, but stops at the first cell which can't be converted.
+    /// The error points at the line and column of that cell.
+    pub fn try_map<U, F: FnMut(&T) -> Result<U, ParseError>>(&self, mut f: F) -> Result<Grid<U>, ParseError> {
+        let mut cells: Vec<U> = Vec::with_capacity(self.cells.len());
+        for (c, value) in self.coords().zip(self.cells.iter()) {
+            cells.push(f(value).map_err(|e| e.at_cell(c))?);
+        }
+
+        Ok(Grid { width: self.width, height: self.height, cells })
+    }
+
+    /// One line per row, each cell converted by `f`.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            s.extend(self.row(y).iter().map(&mut f));
+            s.push('\n');
+        }
+
+        s
+    }
+
+    fn index_of(&self, c: Coord) -> Option<usize> {
+        if self.in_bounds(c) {
+            Some(c.1 as usize * self.width + c.0 as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn find_char(&self, c: char) -> Option<Coord> {
+        self.find(|t| *t == c)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &Self::Output {
+        match self.get(c) {
+            Some(t) => t,
+            None => panic!("{:?} is outside of the {}x{} grid", c, self.width, self.height)
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(c) {
+            Some(t) => t,
+            None => panic!("{:?} is outside of the {}x{} grid", c, width, height)
+        }
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        let lines: Vec<String> = vec!["abc", "def", "ghi", "jkl"].into_iter().map(String::from).collect();
+
+        Grid::parse(&lines, |c| c).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = sample();
+
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid[(0, 3)], 'j');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let lines: Vec<String> = vec!["abc", "de"].into_iter().map(String::from).collect();
+        let error = Grid::parse(&lines, |c| c).unwrap_err();
+
+        assert!(matches!(error, InputError::RaggedGrid { line: 2, expected: 3, found: 2 }));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+
+        let n4: Vec<Coord> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(n4, vec![(1, 0), (0, 1)]);
+
+        let n8: Vec<Coord> = grid.neighbours8((1, 1)).collect();
+        assert_eq!(n8.len(), 8);
+        assert_eq!(grid.neighbours8((2, 3)).count(), 3);
+    }
+
+    #[test]
+    fn test_slices() {
+        let grid = sample();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfil");
+        assert_eq!(grid.diagonal((2, 3)).collect::<String>(), "dhl");
+        assert_eq!(grid.diagonal((0, 0)).collect::<String>(), "aei");
+        assert_eq!(grid.anti_diagonal((0, 2)).collect::<String>(), "ceg");
+        assert_eq!(grid.ray((2, 3), (-1, -1)).collect::<String>(), "lhd");
+    }
+
+    #[test]
+    #[should_panic(expected = "row 4 is outside of the 3x4 grid")]
+    fn test_row_out_of_range() {
+        sample().row(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the 3x4 grid")]
+    fn test_column_out_of_range() {
+        let _ = sample().column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 0 is outside of the 0x2 grid")]
+    fn test_column_of_empty_grid() {
+        let _ = Grid::new(0, 2, '.').column(0);
+    }
+
+    #[test]
+    fn test_find_set_render() {
+        let mut grid = sample();
+
+        assert_eq!(grid.find_char('h'), Some((1, 2)));
+        assert_eq!(grid.find_char('z'), None);
+
+        assert_eq!(grid.set((1, 2), '#'), Some('h'));
+        assert_eq!(grid.set((5, 5), '#'), None);
+        assert_eq!(grid.find_all(|c| *c == '#'), vec![(1, 2)]);
+
+        assert_eq!(grid.to_string(), "abc\ndef\ng#i\njkl\n");
+        assert_eq!(grid.map(|c| c.is_ascii_lowercase()).render(|l| if *l { '.' } else { '#' }), "...\n...\n.#.\n...\n");
+    }
+
+    #[test]
+    fn test_try_map() {
+        let digits = Grid::parse(&[String::from("12"), String::from("34")], |c| c).unwrap();
+        let numbers = digits.try_map(|c| c.to_digit(10).ok_or_else(|| ParseError::new(0, "a digit", &c.to_string()))).unwrap();
+        assert_eq!(numbers[(1, 1)], 4);
+
+        let error = sample().try_map(|c| if *c == 'h' { Err(ParseError::new(0, "not h", "h")) } else { Ok(*c) }).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+}
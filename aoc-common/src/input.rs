@@ -0,0 +1,260 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::str::FromStr;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+
+/// Puzzle input, split into lines. Line endings are normalized to `\n`
+/// and leading/trailing blank lines are removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    lines: Vec<String>,
+    // the number of removed leading blank lines
+    line_offset: usize
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io { path: String, source: io::Error },
+    Empty,
+    NotSingleLine { lines: usize },
+    RaggedGrid { line: usize, expected: usize, found: usize },
+    InvalidNumber { line: usize, token: String }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io { path, source } => write!(f, "could not read '{}': {}", path, source),
+            InputError::Empty => write!(f, "input is empty"),
+            InputError::NotSingleLine { lines } => write!(f, "expected a single line, but input has {} lines", lines),
+            InputError::RaggedGrid { line, expected, found } => {
+                write!(f, "line {}: expected {} columns, found {}", line, expected, found)
+            },
+            InputError::InvalidNumber { line, token } => write!(f, "line {}: '{}' is not a valid number", line, token)
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None
+        }
+    }
+}
+
+impl Input {
+    pub fn from_file(file_name: &str) -> Result<Self, InputError> {
+        let content = fs::read_to_string(file_name)
+            .map_err(|source| InputError::Io { path: String::from(file_name), source })?;
+
+        Ok(Input::parse(&content))
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let content = content.replace("\r\n", "\n");
+        let trimmed = content.trim_start_matches('\n');
+        let line_offset = content.len() - trimmed.len();
+
+        let lines: Vec<String> = trimmed
+            .trim_end()
+            .split('\n')
+            .map(String::from)
+            .collect();
+
+        // an empty file still produces one empty line after splitting
+        if lines.len() == 1 && lines[0].is_empty() {
+            return Input { lines: vec![], line_offset: 0 };
+        }
+
+        Input { lines, line_offset }
+    }
+
+    pub fn lines(&self) -> &Vec<String> {
+        &self.lines
+    }
+
+    /// Line numbers (e.g. of a `ParseError`) count the lines of the input,
+    /// line N of the input is line N + `line_offset` of the file.
+    pub fn line_offset(&self) -> usize {
+        self.line_offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Blocks of lines which are separated by (one or more) blank lines.
+    pub fn sections(&self) -> Vec<&[String]> {
+        self.lines
+            .split(|l| l.trim().is_empty())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    /// Like `sections`, together with the (1-based) line number of the first line of each section.
+    pub fn numbered_sections(&self) -> Vec<(usize, &[String])> {
+        let mut sections: Vec<(usize, &[String])> = vec![];
+        let mut start = 0;
+        for (index, line) in self.lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                if index > start {
+                    sections.push((start + 1, &self.lines[start..index]));
+                }
+                start = index + 1;
+            }
+        }
+        if start < self.lines.len() {
+            sections.push((start + 1, &self.lines[start..]));
+        }
+
+        sections
+    }
+
+    /// Parses every line with `T::from_str`. Errors point at the offending line.
+    pub fn parse_lines<T: FromStr<Err = ParseError>>(&self) -> Result<Vec<T>, ParseError> {
+        self.lines.iter().enumerate()
+            .map(|(index, line)| line.parse::<T>().map_err(|e| e.at_line(index + 1)))
+            .collect()
+    }
+
+    pub fn single_line(&self) -> Result<&String, InputError> {
+        match self.lines.len() {
+            0 => Err(InputError::Empty),
+            1 => Ok(&self.lines[0]),
+            lines => Err(InputError::NotSingleLine { lines })
+        }
+    }
+
+    /// Characters of each line. All lines must have the same length.
+    pub fn grid(&self) -> Result<Grid<char>, InputError> {
+        Grid::parse(&self.lines, |c| c)
+    }
+
+    /// All integers of each line, e.g. `p=0,4 v=3,-3` becomes `[0, 4, 3, -3]`.
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<Vec<T>>, InputError> {
+        self.lines.iter().enumerate()
+            .map(|(index, line)| {
+                extract_numbers(line).iter()
+                    .map(|token| {
+                        token.parse::<T>().map_err(|_| InputError::InvalidNumber {
+                            line: index + 1,
+                            token: String::from(*token)
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn extract_numbers(line: &str) -> Vec<&str> {
+    let mut tokens: Vec<&str> = vec![];
+    let bytes = line.as_bytes();
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let is_negative = bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(|b| b.is_ascii_digit());
+        if !bytes[index].is_ascii_digit() && !is_negative {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        tokens.push(&line[start..index]);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_line_endings() {
+        let input = Input::parse("\r\nab\r\ncd\r\n\r\n");
+
+        assert_eq!(input.lines(), &vec![String::from("ab"), String::from("cd")]);
+        assert_eq!(input.line_offset(), 1);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = Input::parse("a\nb\n\nc\r\n\r\nd\ne\n");
+        let sections = input.sections();
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0], ["a", "b"]);
+        assert_eq!(sections[1], ["c"]);
+        assert_eq!(sections[2], ["d", "e"]);
+    }
+
+    #[test]
+    fn test_numbered_sections() {
+        let input = Input::parse("a\nb\n\n\nc\n\nd");
+        let sections = input.numbered_sections();
+
+        assert_eq!(sections, vec![(1, &input.lines()[0..2]), (5, &input.lines()[4..5]), (7, &input.lines()[6..7])]);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        #[derive(Debug, PartialEq)]
+        struct Digit(u32);
+
+        impl FromStr for Digit {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse::<u32>().map(Digit).map_err(|_| ParseError::new(1, "a digit", s))
+            }
+        }
+
+        assert_eq!(Input::parse("1\n2").parse_lines::<Digit>().unwrap(), vec![Digit(1), Digit(2)]);
+
+        let error = Input::parse("1\nx\n3").parse_lines::<Digit>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(Input::parse("2333133121414131402\n").single_line().unwrap(), "2333133121414131402");
+        assert!(matches!(Input::parse("").single_line(), Err(InputError::Empty)));
+        assert!(matches!(Input::parse("1\n2").single_line(), Err(InputError::NotSingleLine { lines: 2 })));
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Input::parse("#.\n.#").grid().unwrap();
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+
+        let error = Input::parse("#.\n.#.").grid().unwrap_err();
+        assert!(matches!(error, InputError::RaggedGrid { line: 2, expected: 2, found: 3 }));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let numbers = Input::parse("3   4\np=0,4 v=3,-3\nx-y").numbers::<i64>().unwrap();
+        assert_eq!(numbers, vec![vec![3, 4], vec![0, 4, 3, -3], vec![]]);
+
+        let error = Input::parse("1\n-2").numbers::<u64>().unwrap_err();
+        assert!(matches!(error, InputError::InvalidNumber { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = Input::from_file("does-not-exist").unwrap_err();
+
+        assert!(matches!(error, InputError::Io { .. }));
+    }
+}
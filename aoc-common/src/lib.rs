@@ -0,0 +1,12 @@
+pub mod answer;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod search;
+pub mod simulation;
+
+pub use answer::Answer;
+pub use grid::{Coord, Grid};
+pub use input::{Input, InputError};
+pub use parse::ParseError;
+pub use simulation::Simulation;
@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::grid::Coord;
+use crate::input::InputError;
+
+/// Malformed puzzle input: where it is and what was expected there.
+/// Lines and columns start at 1, 0 means the position is not known.
+/// Parsers of a single line only know the column, the line is added by
+/// whoever iterates over the input (see `at_line` and `offset`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: &str, found: &str) -> Self {
+        ParseError { day: None, line: 0, column, expected: String::from(expected), found: String::from(found) }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Moves an error of a section (line 1 is the first line of the section)
+    /// to its position in the whole input.
+    pub fn offset(mut self, first_line: usize) -> Self {
+        if self.line > 0 {
+            self.line += first_line - 1;
+        } else {
+            self.line = first_line;
+        }
+        self
+    }
+
+    /// Position of a cell of a grid, which was parsed from the lines of the input.
+    pub fn at_cell(mut self, c: Coord) -> Self {
+        self.line = c.1 as usize + 1;
+        self.column = c.0 as usize + 1;
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut position: Vec<String> = vec![];
+        if let Some(day) = self.day {
+            position.push(format!("day {}", day));
+        }
+        if self.line > 0 {
+            position.push(format!("line {}", self.line));
+        }
+        if self.column > 0 {
+            position.push(format!("column {}", self.column));
+        }
+        if !position.is_empty() {
+            write!(f, "{}: ", position.join(", "))?;
+        }
+
+        if self.found.is_empty() {
+            write!(f, "expected {}, found nothing", self.expected)
+        } else {
+            write!(f, "expected {}, found '{}'", self.expected, self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<InputError> for ParseError {
+    fn from(error: InputError) -> Self {
+        match error {
+            InputError::Io { path, source } => ParseError::new(0, &format!("a readable file '{}'", path), &source.to_string()),
+            InputError::Empty => ParseError::new(1, "some input", "").at_line(1),
+            InputError::NotSingleLine { lines } => {
+                ParseError::new(1, "a single line", &format!("{} lines", lines)).at_line(2)
+            },
+            InputError::RaggedGrid { line, expected, found } => {
+                let column = expected.min(found) + 1;
+                ParseError::new(column, &format!("{} columns", expected), &format!("{} columns", found)).at_line(line)
+            },
+            InputError::InvalidNumber { line, token } => ParseError::new(0, "a number", &token).at_line(line)
+        }
+    }
+}
+
+/// Parses a token which starts at `column` of its line.
+pub fn number<T: FromStr>(token: &str, column: usize) -> Result<T, ParseError> {
+    token.trim().parse::<T>().map_err(|_| ParseError::new(column, "a number", token))
+}
+
+/// A token together with the column it starts at.
+pub type Token<'a> = (&'a str, usize);
+
+/// Splits at the first `separator`. The column of the second part is `column`
+/// plus the length of the first part and the separator.
+pub fn split_once<'a>(s: &'a str, separator: &str, column: usize) -> Result<(Token<'a>, Token<'a>), ParseError> {
+    match s.split_once(separator) {
+        Some((first, second)) => Ok(((first, column), (second, column + first.len() + separator.len()))),
+        None => Err(ParseError::new(column + s.len(), &format!("'{}'", separator), s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(5, "a number", "x").at_line(3).in_day(7);
+        assert_eq!(error.to_string(), "day 7, line 3, column 5: expected a number, found 'x'");
+
+        let error = ParseError::new(0, "a lock or a key", "").offset(4);
+        assert_eq!(error.to_string(), "line 4: expected a lock or a key, found nothing");
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(number::<u64>("42", 1), Ok(42));
+        assert_eq!(number::<u64>("4x", 3).unwrap_err().column, 3);
+
+        let ((lhs, _), (rhs, rhs_column)) = split_once("190: 10 19", ": ", 1).unwrap();
+        assert_eq!((lhs, rhs, rhs_column), ("190", "10 19", 6));
+        assert_eq!(split_once("190 10", ": ", 1).unwrap_err().column, 7);
+
+        let error = ParseError::new(2, "a number", "x").at_line(2).offset(10);
+        assert_eq!(error.line, 11);
+    }
+}
@@ -0,0 +1,261 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Outcome of a search: the cost of every visited state and, for each of them,
+/// all predecessors which lead to it with that (minimal) cost.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    goals: Vec<S>,
+    costs: HashMap<S, i64>,
+    predecessors: HashMap<S, Vec<S>>
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    /// First goal state which was reached, `None` if no goal is reachable.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// All goal states which were reached with the minimal cost.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Cost of the cheapest goal.
+    pub fn cost(&self) -> Option<i64> {
+        self.goal().and_then(|g| self.cost_to(g))
+    }
+
+    pub fn cost_to(&self, state: &S) -> Option<i64> {
+        self.costs.get(state).copied()
+    }
+
+    /// Costs of all visited states. If no goal is reachable (or the goal predicate
+    /// never matches), this is the complete distance field from the start.
+    pub fn costs(&self) -> &HashMap<S, i64> {
+        &self.costs
+    }
+
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map(|p| p.as_slice()).unwrap_or(&[])
+    }
+
+    /// One cheapest path from the start to the first goal, both inclusive.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal().and_then(|g| self.path_to(g))
+    }
+
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return None;
+        }
+
+        let mut path: Vec<S> = vec![state.clone()];
+        let mut current = state;
+        while let Some(p) = self.predecessors(current).first() {
+            path.push(p.clone());
+            current = p;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every state which lies on any cheapest path from the start to one of `targets`.
+    pub fn states_on_paths(&self, targets: &[S]) -> HashSet<S> {
+        let mut states: HashSet<S> = HashSet::new();
+        let mut stack: Vec<S> = targets.iter()
+            .filter(|t| self.costs.contains_key(t))
+            .cloned()
+            .collect();
+
+        while let Some(s) = stack.pop() {
+            if !states.insert(s.clone()) {
+                continue;
+            }
+            stack.extend(self.predecessors(&s).iter().cloned());
+        }
+
+        states
+    }
+}
+
+/// Dijkstra's algorithm. `successors` returns the reachable states together with
+/// the (non-negative) cost to get there. Stops as soon as all goals of minimal cost are known.
+pub fn dijkstra<S, I, FS, FG>(start: S, successors: FS, is_goal: FG) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search. The `heuristic` must never overestimate the remaining cost to a goal,
+/// otherwise the result is not guaranteed to be the cheapest.
+pub fn astar<S, I, FS, FH, FG>(start: S, mut successors: FS, mut heuristic: FH, mut is_goal: FG) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+    FS: FnMut(&S) -> I,
+    FH: FnMut(&S) -> i64,
+    FG: FnMut(&S) -> bool
+{
+    let mut result = SearchResult { goals: vec![], costs: HashMap::new(), predecessors: HashMap::new() };
+    // the heap only stores indices into `states`, so states don't need to be ordered
+    let mut states: Vec<S> = vec![];
+    let mut queue: BinaryHeap<Reverse<(i64, i64, usize)>> = BinaryHeap::new();
+
+    result.costs.insert(start.clone(), 0);
+    queue.push(Reverse((heuristic(&start), 0, 0)));
+    states.push(start);
+
+    while let Some(Reverse((priority, cost, index))) = queue.pop() {
+        if let Some(goal_cost) = result.cost() {
+            if priority > goal_cost {
+                break;
+            }
+        }
+
+        let current = states[index].clone();
+        if result.costs[&current] < cost {
+            continue; // outdated entry, there was a cheaper way to this state
+        }
+
+        if is_goal(&current) {
+            if !result.goals.contains(&current) {
+                result.goals.push(current);
+            }
+            continue;
+        }
+
+        for (next, step_cost) in successors(&current) {
+            let next_cost = cost + step_cost;
+            match result.costs.get(&next) {
+                Some(&old_cost) if old_cost < next_cost => continue,
+                Some(&old_cost) if old_cost == next_cost => {
+                    result.predecessors.entry(next).or_default().push(current.clone());
+                    continue;
+                },
+                _ => {}
+            }
+
+            result.costs.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), vec![current.clone()]);
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, states.len())));
+            states.push(next);
+        }
+    }
+
+    result
+}
+
+/// Breadth-first search, every step costs 1.
+pub fn bfs<S, I, FS, FG>(start: S, mut successors: FS, mut is_goal: FG) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool
+{
+    let mut result = SearchResult { goals: vec![], costs: HashMap::new(), predecessors: HashMap::new() };
+    let mut queue: VecDeque<S> = VecDeque::new();
+
+    result.costs.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(current) = queue.pop_front() {
+        let cost = result.costs[&current];
+        if let Some(goal_cost) = result.cost() {
+            if cost > goal_cost {
+                break;
+            }
+        }
+        if is_goal(&current) {
+            result.goals.push(current);
+            continue;
+        }
+
+        for next in successors(&current) {
+            match result.costs.get(&next) {
+                Some(&old_cost) if old_cost == cost + 1 => {
+                    result.predecessors.entry(next).or_default().push(current.clone());
+                },
+                Some(_) => {},
+                None => {
+                    result.costs.insert(next.clone(), cost + 1);
+                    result.predecessors.insert(next.clone(), vec![current.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::NEIGHBOURS_4;
+
+    // a -1-> b -1-> d
+    // a -1-> c -1-> d
+    // a -5-> e -1-> d
+    fn graph(node: &char) -> Vec<(char, i64)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 1), ('e', 5)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 1)],
+            'e' => vec![('d', 1)],
+            _ => vec![]
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra('a', graph, |n| *n == 'd');
+
+        assert_eq!(result.cost(), Some(2));
+        assert_eq!(result.path().unwrap().len(), 3);
+        assert_eq!(result.predecessors(&'d').len(), 2);
+
+        let on_paths = result.states_on_paths(result.goals());
+        assert_eq!(on_paths, HashSet::from(['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let result = dijkstra('a', graph, |n| *n == 'z');
+
+        assert_eq!(result.goal(), None);
+        assert_eq!(result.cost(), None);
+        assert_eq!(result.cost_to(&'e'), Some(5));
+    }
+
+    #[test]
+    fn test_astar_and_bfs() {
+        // open 5x5 field, every step costs 1
+        let successors = |c: &(i64, i64)| -> Vec<(i64, i64)> {
+            NEIGHBOURS_4.iter()
+                .map(|o| (c.0 + o.0, c.1 + o.1))
+                .filter(|n| n.0 >= 0 && n.1 >= 0 && n.0 < 5 && n.1 < 5)
+                .collect()
+        };
+        let end = (4, 4);
+
+        let a = astar((0, 0), |c| successors(c).into_iter().map(|n| (n, 1)), |c| (end.0 - c.0) + (end.1 - c.1), |c| *c == end);
+        assert_eq!(a.cost(), Some(8));
+        assert_eq!(a.path().unwrap().first(), Some(&(0, 0)));
+
+        let b = bfs((0, 0), successors, |c| *c == end);
+        assert_eq!(b.cost(), Some(8));
+        assert_eq!(b.states_on_paths(b.goals()).len(), 25);
+
+        let field = bfs((0, 0), successors, |_| false);
+        assert_eq!(field.costs().len(), 25);
+        assert_eq!(field.cost_to(&(2, 3)), Some(5));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::grid::Grid;
+
+/// A puzzle which can be played frame by frame, e.g. by `aoc visualize`.
+/// Frame 0 is the state before the first step. Simulations are deterministic,
+/// so a player can go back by replaying a fresh simulation.
+pub trait Simulation {
+    /// Advances by one frame. Returns `false` (and changes nothing) once the
+    /// simulation is over.
+    fn step(&mut self) -> bool;
+
+    /// The current state, one character per cell.
+    fn frame(&self) -> Grid<char>;
+
+    /// A short description of the current state, e.g. the elapsed seconds.
+    fn status(&self) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter {
+        count: usize
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.count == 3 {
+                return false;
+            }
+            self.count += 1;
+            true
+        }
+
+        fn frame(&self) -> Grid<char> {
+            let mut grid = Grid::new(3, 1, '.');
+            (0..self.count).for_each(|x| grid[(x as i64, 0)] = '#');
+            grid
+        }
+
+        fn status(&self) -> String {
+            format!("{} steps", self.count)
+        }
+    }
+
+    #[test]
+    fn test_simulation() {
+        let mut simulation: Box<dyn Simulation> = Box::new(Counter { count: 0 });
+        while simulation.step() {}
+
+        assert_eq!(simulation.frame().to_string(), "###\n");
+        assert_eq!(simulation.status(), "3 steps");
+        assert!(!simulation.step());
+    }
+}
@@ -0,0 +1,74 @@
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use aoc_common::{Input, ParseError};
+
+// Every day on its checked-in example. Run a single day with
+// `cargo bench -p aoc -- day-07`.
+
+fn input(day: &str, file: &str) -> Input {
+    let path = format!("{}/../{}/{}", env!("CARGO_MANIFEST_DIR"), day, file);
+    Input::from_file(&path).unwrap()
+}
+
+fn bench_parse<M>(c: &mut Criterion, day: &str, file: &str, parse: impl Fn(&Input) -> Result<M, ParseError>) {
+    let input = input(day, file);
+    c.bench_function(&format!("{}/parse", day), |b| b.iter(|| parse(black_box(&input))));
+}
+
+fn bench_part<M, R>(c: &mut Criterion, day: &str, part: u8, file: &str, parse: impl Fn(&Input) -> Result<M, ParseError>, solve: impl Fn(&M) -> R) {
+    let model = parse(&input(day, file)).unwrap();
+    c.bench_function(&format!("{}/part{}", day, part), |b| b.iter(|| solve(black_box(&model))));
+}
+
+// parse, part 1 and part 2 on the same file
+macro_rules! bench_day {
+    ($c:expr, $day:ident, $file:expr) => {
+        bench_day!($c, $day, $file, $day::parse)
+    };
+    ($c:expr, $day:ident, $file:expr, $parse:expr) => {{
+        let name = stringify!($day).replace('_', "-");
+        bench_parse($c, &name, $file, $parse);
+        bench_part($c, &name, 1, $file, $parse, $day::part1);
+        bench_part($c, &name, 2, $file, $parse, $day::part2);
+    }};
+}
+
+fn days(c: &mut Criterion) {
+    bench_day!(c, day_01, "test-input");
+    bench_day!(c, day_02, "test-input");
+    bench_parse(c, "day-03", "test-input", day_03::parse);
+    bench_part(c, "day-03", 1, "test-input", day_03::parse, day_03::part1);
+    bench_part(c, "day-03", 2, "test-input-part2", day_03::parse, day_03::part2);
+    bench_day!(c, day_04, "test-input");
+    bench_day!(c, day_05, "test-input");
+    bench_day!(c, day_06, "test-input");
+    bench_day!(c, day_07, "test-input");
+    bench_day!(c, day_08, "test-input");
+    bench_day!(c, day_09, "test-input");
+    bench_day!(c, day_10, "test-input");
+    bench_day!(c, day_11, "test-input");
+    bench_day!(c, day_12, "test-input-4");
+    bench_day!(c, day_13, "test-input");
+    bench_day!(c, day_14, "test-input", |i| day_14::parse_with_grid(i, (11, 7)));
+    bench_day!(c, day_15, "test-input-2");
+    bench_day!(c, day_16, "test-input-2");
+    bench_parse(c, "day-17", "test-input", day_17::parse);
+    bench_part(c, "day-17", 1, "test-input", day_17::parse, day_17::part1);
+    bench_part(c, "day-17", 2, "test-input-2", day_17::parse, day_17::part2);
+    bench_day!(c, day_18, "test-input", |i| day_18::parse_with(i, (6, 6), 12));
+    bench_day!(c, day_19, "test-input");
+    bench_day!(c, day_20, "test-input", |i| day_20::parse_with(i, 50));
+    bench_day!(c, day_21, "test-input");
+    bench_parse(c, "day-22", "test-input", day_22::parse);
+    bench_part(c, "day-22", 1, "test-input", day_22::parse, day_22::part1);
+    bench_part(c, "day-22", 2, "test-input-2", day_22::parse, day_22::part2);
+    bench_day!(c, day_23, "test-input");
+    // there is no example for part 2, it needs the wires of a real adder
+    bench_parse(c, "day-24", "test-input", day_24::parse);
+    bench_part(c, "day-24", 1, "test-input", day_24::parse, day_24::part1);
+    bench_parse(c, "day-25", "test-input", day_25::parse);
+    bench_part(c, "day-25", 1, "test-input", day_25::parse, day_25::part1);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
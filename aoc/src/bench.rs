@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use aoc_common::Input;
+use crate::days::{self, SolveError};
+use crate::fetch::Cache;
+
+const USAGE: &str = "Usage: aoc bench [<day>] [--iterations <n>] [--save <file>] [--baseline <file>]";
+
+// runs which are not measured, so caches and the branch predictor are warm
+const WARM_UP_RUNS: usize = 2;
+const DEFAULT_ITERATIONS: usize = 10;
+// a phase is a regression if its median is this much slower than the baseline
+const REGRESSION_THRESHOLD: f64 = 0.2;
+
+// median nanoseconds per day ("day-07") and phase ("parse", "part1", ...)
+type Baseline = BTreeMap<String, BTreeMap<String, u64>>;
+
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub parts: Vec<(u8, Vec<Duration>)>
+}
+
+impl Timings {
+    fn phases(&self) -> Vec<(String, &Vec<Duration>)> {
+        let mut phases = vec![(String::from("parse"), &self.parse)];
+        self.parts.iter().for_each(|(p, samples)| phases.push((format!("part{}", p), samples)));
+
+        phases
+    }
+}
+
+// `iterations` samples of `f`, after a few runs which are thrown away
+pub fn measure<T, F: FnMut() -> T>(iterations: usize, mut f: F) -> Vec<Duration> {
+    for _ in 0..WARM_UP_RUNS {
+        black_box(f());
+    }
+
+    (0..iterations).map(|_| {
+        let start = Instant::now();
+        black_box(f());
+        start.elapsed()
+    }).collect()
+}
+
+struct Settings {
+    days: Vec<u8>,
+    iterations: usize,
+    save: Option<String>,
+    baseline: Option<String>
+}
+
+// without a day, every day with a cached puzzle input is measured
+pub fn run(args: &[String]) -> ExitCode {
+    let settings = match parse_args(args) {
+        Ok(settings) => settings,
+        Err(message) => {
+            eprintln!("{}", message);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let baseline: Option<Baseline> = match &settings.baseline {
+        Some(path) => match read_baseline(path) {
+            Ok(baseline) => Some(baseline),
+            Err(message) => {
+                eprintln!("Error: {}", message);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None
+    };
+
+    let mut medians: Baseline = BTreeMap::new();
+    let mut regressions = 0;
+    println!("{:>3}  {:<6} {:>12} {:>12} {:>12} {:>8}", "Day", "Phase", "Median", "Min", "Baseline", "Change");
+
+    let cache = Cache::workspace();
+    for day in &settings.days {
+        let path = cache.path(*day);
+        if !path.exists() {
+            println!("{:>3}  no input at {}, skipped", day, path.display());
+            continue;
+        }
+        let input = match Input::from_file(&path.display().to_string()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let timings = match days::time(*day, &input, settings.iterations) {
+            Ok(timings) => timings,
+            Err(SolveError::Parse(e)) => {
+                crate::print_parse_error(&e, input.lines(), input.line_offset());
+                return ExitCode::FAILURE;
+            },
+            Err(SolveError::NoSolution) => {
+                println!("{:>3}  no solution, skipped", day);
+                continue;
+            }
+        };
+
+        let key = format!("day-{:02}", day);
+        for (phase, samples) in timings.phases() {
+            let median = median(samples);
+            let min = samples.iter().min().copied().unwrap_or_default();
+            let previous = baseline.as_ref()
+                .and_then(|b| b.get(&key))
+                .and_then(|b| b.get(&phase))
+                .map(|nanos| Duration::from_nanos(*nanos));
+
+            let (baseline_column, change_column) = match previous {
+                Some(previous) => {
+                    let change = median.as_secs_f64() / previous.as_secs_f64().max(f64::EPSILON) - 1.0;
+                    let marker = if change > REGRESSION_THRESHOLD {
+                        regressions += 1;
+                        "  <- regression"
+                    } else {
+                        ""
+                    };
+                    (format!("{:.2?}", previous), format!("{:+.1}%{}", change * 100.0, marker))
+                },
+                None => (String::from("-"), String::from("-"))
+            };
+            println!("{:>3}  {:<6} {:>12} {:>12} {:>12} {:>8}",
+                     day, phase, format!("{:.2?}", median), format!("{:.2?}", min), baseline_column, change_column);
+
+            medians.entry(key.clone()).or_default().insert(phase, median.as_nanos() as u64);
+        }
+    }
+
+    if let Some(path) = &settings.save {
+        if let Err(message) = write_baseline(path, &medians) {
+            eprintln!("Error: {}", message);
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline to {}", path);
+    }
+
+    if regressions > 0 {
+        eprintln!("{} phase(s) are more than {}% slower than the baseline", regressions, REGRESSION_THRESHOLD * 100.0);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn parse_args(args: &[String]) -> Result<Settings, String> {
+    let mut settings = Settings { days: (1..=25).collect(), iterations: DEFAULT_ITERATIONS, save: None, baseline: None };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = args.next().ok_or("--iterations needs a value")?;
+                settings.iterations = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("'{}' is not a valid number of iterations", value))
+                };
+            },
+            "--save" => settings.save = Some(args.next().ok_or("--save needs a file")?.clone()),
+            "--baseline" => settings.baseline = Some(args.next().ok_or("--baseline needs a file")?.clone()),
+            day => {
+                let day = day.parse::<u8>().map_err(|_| format!("'{}' is not a valid day", day))?;
+                settings.days = vec![day];
+            }
+        }
+    }
+
+    Ok(settings)
+}
+
+fn median(samples: &[Duration]) -> Duration {
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+
+    sorted.get(sorted.len() / 2).copied().unwrap_or_default()
+}
+
+fn read_baseline(path: &str) -> Result<Baseline, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("can't read baseline '{}': {}", path, e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("invalid baseline '{}': {}", path, e))
+}
+
+fn write_baseline(path: &str, baseline: &Baseline) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(baseline).map_err(|e| e.to_string())?;
+
+    fs::write(path, content + "\n").map_err(|e| format!("can't write baseline '{}': {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median() {
+        let samples: Vec<Duration> = [5, 1, 3].iter().map(|n| Duration::from_millis(*n)).collect();
+
+        assert_eq!(median(&samples), Duration::from_millis(3));
+        assert_eq!(measure(4, || 1 + 1).len(), 4);
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args: Vec<String> = ["7", "--iterations", "3", "--baseline", "b.json"].iter().map(|s| s.to_string()).collect();
+        let settings = parse_args(&args).unwrap();
+
+        assert_eq!(settings.days, vec![7]);
+        assert_eq!(settings.iterations, 3);
+        assert_eq!(settings.baseline, Some(String::from("b.json")));
+        assert!(parse_args(&[String::from("--iterations")]).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use day_17::assembly;
+use day_17::debugger::{Command, Debugger};
+use day_17::decompiler;
+
+const USAGE: &str = "Usage: aoc d17 disasm [<input>]
+       aoc d17 asm <source>
+       aoc d17 debug [<input>]
+       aoc d17 decompile [<input>]";
+
+// tools for the program of the three-bit computer of day 17
+pub fn run(args: &[String]) -> ExitCode {
+    match args.first().map(|s| s.as_str()) {
+        Some("disasm") if args.len() <= 2 => disasm(args.get(1).cloned()),
+        Some("asm") if args.len() == 2 => asm(&args[1]),
+        Some("debug") if args.len() <= 2 => debug(args.get(1).cloned()),
+        Some("decompile") if args.len() <= 2 => decompile(args.get(1).cloned()),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// prints the assembly of the `Program:` line. Diagnostics are comments in the listing,
+// they don't make the command fail.
+fn disasm(path: Option<String>) -> ExitCode {
+    let input = match crate::read_input(17, path) {
+        Ok(input) => input,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let program = match day_17::read_program(&input) {
+        Ok(program) => program,
+        Err(e) => {
+            crate::print_parse_error(&e.in_day(17), input.lines(), input.line_offset());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let disassembly = assembly::disassemble(&program);
+    print!("{}", disassembly);
+    if !disassembly.diagnostics.is_empty() {
+        eprintln!("{} diagnostic(s)", disassembly.diagnostics.len());
+    }
+
+    ExitCode::SUCCESS
+}
+
+// prints the puzzle input (registers and program) of the assembly source
+fn asm(path: &str) -> ExitCode {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("Error: {}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match assembly::assemble(&source) {
+        Ok(assembly) => {
+            print!("{}", assembly);
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            let lines: Vec<String> = source.lines().map(String::from).collect();
+            crate::print_parse_error(&e, &lines, 0);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// a command line debugger for the cpu of the input, until 'quit' or the end of stdin
+fn debug(path: Option<String>) -> ExitCode {
+    let input = match crate::read_input(17, path) {
+        Ok(input) => input,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+    let cpu = match day_17::parse(&input) {
+        Ok(cpu) => cpu,
+        Err(e) => {
+            crate::print_parse_error(&e.in_day(17), input.lines(), input.line_offset());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut debugger = Debugger::new(&cpu);
+    println!("{}", debugger.execute(&Command::Print));
+    println!("Type 'help' for the commands");
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("(d17) ");
+        let _ = io::stdout().flush();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break
+        };
+        match line.parse::<Command>() {
+            Ok(Command::Quit) => break,
+            Ok(command) => println!("{}", debugger.execute(&command)),
+            Err(message) => println!("{}", message)
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// prints the formula of every output of a single-loop program and the shift of A per iteration
+fn decompile(path: Option<String>) -> ExitCode {
+    let input = match crate::read_input(17, path) {
+        Ok(input) => input,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+    let cpu = match day_17::parse(&input) {
+        Ok(cpu) => cpu,
+        Err(e) => {
+            crate::print_parse_error(&e.in_day(17), input.lines(), input.line_offset());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match decompiler::decompile(&cpu) {
+        Ok(program_loop) => {
+            print!("{}", program_loop);
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::{Answer, Input, ParseError, Simulation};
+use crate::bench::{measure, Timings};
+
+// Dispatches a day to the matching crate. The input is parsed once and the
+// requested part (or every part, if none was given) is solved on that model.
+// Puzzles which need extra parameters (grid sizes, number of fallen bytes, ...)
+// default to the values of the real puzzle input in their `parse`.
+
+// runs `$action!` with the crate of `$day` and the parts it solves
+macro_rules! dispatch {
+    ($day:expr, $action:ident, $($arg:expr),+) => {
+        match $day {
+            1 => $action!(day_01, $($arg),+),
+            2 => $action!(day_02, $($arg),+),
+            3 => $action!(day_03, $($arg),+),
+            4 => $action!(day_04, $($arg),+),
+            5 => $action!(day_05, $($arg),+),
+            6 => $action!(day_06, $($arg),+),
+            7 => $action!(day_07, $($arg),+),
+            8 => $action!(day_08, $($arg),+),
+            9 => $action!(day_09, $($arg),+),
+            10 => $action!(day_10, $($arg),+),
+            11 => $action!(day_11, $($arg),+),
+            12 => $action!(day_12, $($arg),+),
+            13 => $action!(day_13, $($arg),+),
+            14 => $action!(day_14, $($arg),+),
+            15 => $action!(day_15, $($arg),+),
+            16 => $action!(day_16, $($arg),+),
+            17 => $action!(day_17, $($arg),+),
+            18 => $action!(day_18, $($arg),+),
+            19 => $action!(day_19, $($arg),+),
+            20 => $action!(day_20, $($arg),+),
+            21 => $action!(day_21, $($arg),+),
+            22 => $action!(day_22, $($arg),+),
+            23 => $action!(day_23, $($arg),+),
+            24 => $action!(day_24, $($arg),+),
+            25 => $action!(day_25, $($arg),+; 1 => part1),
+            _ => return Err(SolveError::NoSolution)
+        }
+    };
+}
+
+macro_rules! solve {
+    ($day:ident, $input:expr, $part:expr) => {
+        solve!($day, $input, $part; 1 => part1, 2 => part2)
+    };
+    ($day:ident, $input:expr, $part:expr; $($n:literal => $solver:ident),+) => {{
+        let parts: Vec<u8> = match $part {
+            Some(p) if [$($n),+].contains(&p) => vec![p],
+            Some(_) => return Err(SolveError::NoSolution),
+            None => vec![$($n),+]
+        };
+
+        let model = $day::parse($input).map_err(SolveError::Parse)?;
+        parts.into_iter().map(|p| {
+            let result = match p {
+                $($n => $day::$solver(&model),)+
+                _ => unreachable!()
+            };
+            (p, result)
+        }).collect::<Vec<(u8, Answer)>>()
+    }};
+}
+
+// parse is timed on its own, the parts are timed on a single parsed model
+macro_rules! time {
+    ($day:ident, $input:expr, $iterations:expr) => {
+        time!($day, $input, $iterations; 1 => part1, 2 => part2)
+    };
+    ($day:ident, $input:expr, $iterations:expr; $($n:literal => $solver:ident),+) => {{
+        let model = $day::parse($input).map_err(SolveError::Parse)?;
+        let parse = measure($iterations, || $day::parse($input));
+        let parts = vec![$(($n, measure($iterations, || $day::$solver(&model))),)+];
+
+        Timings { parse, parts }
+    }};
+}
+
+pub enum SolveError {
+    // the day or the part doesn't exist
+    NoSolution,
+    Parse(ParseError)
+}
+
+// results as (part, answer) pairs
+pub fn solve(day: u8, part: Option<u8>, input: &Input) -> Result<Vec<(u8, Answer)>, SolveError> {
+    solve_day(day, part, input).map_err(|e| in_day(e, day))
+}
+
+pub fn time(day: u8, input: &Input, iterations: usize) -> Result<Timings, SolveError> {
+    time_day(day, input, iterations).map_err(|e| in_day(e, day))
+}
+
+fn solve_day(day: u8, part: Option<u8>, input: &Input) -> Result<Vec<(u8, Answer)>, SolveError> {
+    let results = dispatch!(day, solve, input, part);
+
+    Ok(results)
+}
+
+fn time_day(day: u8, input: &Input, iterations: usize) -> Result<Timings, SolveError> {
+    let timings = dispatch!(day, time, input, iterations);
+
+    Ok(timings)
+}
+
+// a fresh simulation of the parsed input on every call, so a player can start over
+pub type Simulations = Box<dyn Fn() -> Box<dyn Simulation>>;
+
+// the part only matters where the parts simulate differently (the wide warehouse of day 15)
+pub fn simulate(day: u8, part: Option<u8>, input: &Input) -> Result<Simulations, SolveError> {
+    simulate_day(day, part, input).map_err(|e| in_day(e, day))
+}
+
+fn simulate_day(day: u8, part: Option<u8>, input: &Input) -> Result<Simulations, SolveError> {
+    let simulations: Simulations = match day {
+        6 => {
+            let map = day_06::parse(input).map_err(SolveError::Parse)?;
+            Box::new(move || Box::new(day_06::Patrol::new(&map)))
+        },
+        14 => {
+            let map = day_14::parse(input).map_err(SolveError::Parse)?;
+            Box::new(move || Box::new(day_14::Motion::new(&map)))
+        },
+        15 => {
+            let warehouse = day_15::parse(input).map_err(SolveError::Parse)?;
+            let wide = part == Some(2);
+            Box::new(move || Box::new(day_15::Moves::new(&warehouse, wide)))
+        },
+        _ => return Err(SolveError::NoSolution)
+    };
+
+    Ok(simulations)
+}
+
+fn in_day(error: SolveError, day: u8) -> SolveError {
+    match error {
+        SolveError::Parse(e) => SolveError::Parse(e.in_day(day)),
+        e => e
+    }
+}
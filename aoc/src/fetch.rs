@@ -0,0 +1,251 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+
+const USAGE: &str = "Usage: aoc fetch <day> [--from <directory>] [--force]";
+
+pub const YEAR: u16 = 2024;
+// git-ignored, relative to the workspace root
+const CACHE_DIRECTORY: &str = "inputs";
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+#[derive(Debug)]
+pub enum FetchError {
+    Io { path: String, source: std::io::Error },
+    MissingSession,
+    Http { url: String, message: String }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Io { path, source } => write!(f, "{}: {}", path, source),
+            FetchError::MissingSession => write!(f, "set {} to the session cookie of adventofcode.com", SESSION_VARIABLE),
+            FetchError::Http { url, message } => write!(f, "can't download {}: {}", url, message)
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+// where the puzzle inputs come from
+pub trait InputSource {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+// downloads from adventofcode.com with curl, which is authenticated by the session cookie
+pub struct HttpSource {
+    session: String
+}
+
+impl HttpSource {
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = env::var(SESSION_VARIABLE).map_err(|_| FetchError::MissingSession)?;
+
+        Ok(HttpSource { session })
+    }
+}
+
+impl InputSource for HttpSource {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
+        let error = |e: std::io::Error| FetchError::Http { url: url.clone(), message: e.to_string() };
+        // the cookie goes to curl's stdin, on the command line other users could read it (ps)
+        let mut curl = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--location"])
+            .args(["--user-agent", "aoc-2024 runner (aoc fetch)"])
+            .args(["--config", "-"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(error)?;
+        if let Some(mut stdin) = curl.stdin.take() {
+            stdin.write_all(cookie_config(&self.session).as_bytes()).map_err(error)?;
+        }
+        let output = curl.wait_with_output().map_err(error)?;
+
+        if !output.status.success() {
+            let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(FetchError::Http { url, message });
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+// a curl config file which sends the session cookie
+fn cookie_config(session: &str) -> String {
+    let escaped = session.replace('\\', "\\\\").replace('"', "\\\"");
+
+    format!("cookie = \"session={}\"\n", escaped)
+}
+
+// inputs which are already on disk, laid out like the cache (<year>/day-XX.txt).
+// For working offline and for tests.
+pub struct DirectorySource {
+    root: PathBuf
+}
+
+impl DirectorySource {
+    pub fn new(root: &Path) -> Self {
+        DirectorySource { root: root.to_path_buf() }
+    }
+}
+
+impl InputSource for DirectorySource {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = input_path(&self.root, year, day);
+
+        fs::read_to_string(&path).map_err(|source| FetchError::Io { path: path.display().to_string(), source })
+    }
+}
+
+fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day-{:02}.txt", day))
+}
+
+pub struct Cache {
+    root: PathBuf
+}
+
+impl Cache {
+    pub fn new(root: &Path) -> Self {
+        Cache { root: root.to_path_buf() }
+    }
+
+    pub fn workspace() -> Self {
+        Cache::new(&crate::workspace_root().join(CACHE_DIRECTORY))
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        input_path(&self.root, YEAR, day)
+    }
+
+    // downloads the input only if it's not cached yet (or `force` is set)
+    pub fn fetch(&self, day: u8, source: &dyn InputSource, force: bool) -> Result<PathBuf, FetchError> {
+        let path = self.path(day);
+        if path.exists() && !force {
+            return Ok(path);
+        }
+
+        let content = source.fetch(YEAR, day)?;
+        let io_error = |source| FetchError::Io { path: path.display().to_string(), source };
+        fs::create_dir_all(path.parent().unwrap()).map_err(io_error)?;
+        fs::write(&path, content).map_err(io_error)?;
+
+        Ok(path)
+    }
+}
+
+struct Settings {
+    day: u8,
+    from: Option<String>,
+    force: bool
+}
+
+pub fn run(args: &[String]) -> ExitCode {
+    let Settings { day, from, force } = match parse_args(args) {
+        Ok(settings) => settings,
+        Err(message) => {
+            eprintln!("{}", message);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source: Box<dyn InputSource> = match from {
+        Some(directory) => Box::new(DirectorySource::new(Path::new(&directory))),
+        None => match HttpSource::from_env() {
+            Ok(source) => Box::new(source),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    match Cache::workspace().fetch(day, source.as_ref(), force) {
+        Ok(path) => {
+            println!("Input of day {} is at {}", day, path.display());
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Settings, String> {
+    let mut day: Option<u8> = None;
+    let mut from: Option<String> = None;
+    let mut force = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--from" => from = Some(args.next().ok_or("--from needs a directory")?.clone()),
+            "--force" => force = true,
+            d => day = Some(d.parse::<u8>().ok().filter(|d| (1..=25).contains(d)).ok_or_else(|| format!("'{}' is not a valid day", d))?)
+        }
+    }
+    let day = day.ok_or("a day is missing")?;
+
+    Ok(Settings { day, from, force })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_fetch() {
+        let fixtures = temp_directory("fixtures");
+        fs::create_dir_all(fixtures.join("2024")).unwrap();
+        fs::write(fixtures.join("2024/day-07.txt"), "190: 10 19\n").unwrap();
+
+        let cache = Cache::new(&temp_directory("cache"));
+        let source = DirectorySource::new(&fixtures);
+        let path = cache.fetch(7, &source, false).unwrap();
+        assert_eq!(path, cache.path(7));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "190: 10 19\n");
+
+        // cached inputs are not fetched again
+        fs::write(fixtures.join("2024/day-07.txt"), "changed\n").unwrap();
+        cache.fetch(7, &source, false).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "190: 10 19\n");
+        cache.fetch(7, &source, true).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "changed\n");
+
+        assert!(matches!(cache.fetch(8, &source, false), Err(FetchError::Io { .. })));
+    }
+
+    #[test]
+    fn test_cookie_config() {
+        assert_eq!(cookie_config("53616c74"), "cookie = \"session=53616c74\"\n");
+        assert_eq!(cookie_config("a\"b\\c"), "cookie = \"session=a\\\"b\\\\c\"\n");
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args: Vec<String> = ["7", "--from", "inputs", "--force"].iter().map(|a| a.to_string()).collect();
+        let settings = parse_args(&args).unwrap();
+
+        assert_eq!((settings.day, settings.from, settings.force), (7, Some(String::from("inputs")), true));
+        assert!(parse_args(&[String::from("7"), String::from("--from")]).is_err());
+        assert!(parse_args(&[String::from("26")]).is_err());
+        assert!(parse_args(&[]).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use aoc_common::{Answer, Input, ParseError};
+use days::SolveError;
+use fetch::Cache;
+
+mod bench;
+mod d17;
+mod days;
+mod fetch;
+mod scaffold;
+mod visualize;
+
+const USAGE: &str = "Usage: aoc run <day> [<part>] [<input>]
+       aoc fetch <day> [--from <directory>] [--force]
+       aoc new <day>
+       aoc bench [<day>] [--iterations <n>] [--save <file>] [--baseline <file>]
+       aoc visualize <day> [<part>] [<input>]
+       aoc d17 disasm [<input>]
+       aoc d17 asm <source>
+       aoc d17 debug [<input>]
+       aoc d17 decompile [<input>]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("fetch") => fetch::run(&args[1..]),
+        Some("new") => scaffold::run(&args[1..]),
+        Some("bench") => bench::run(&args[1..]),
+        Some("visualize") => visualize::run(&args[1..]),
+        Some("d17") => d17::run(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// the directory with the Cargo.toml of the workspace, found from the current directory upwards
+fn workspace_root() -> PathBuf {
+    let current = env::current_dir().unwrap_or_default();
+    let is_workspace = |d: &Path| {
+        fs::read_to_string(d.join("Cargo.toml")).is_ok_and(|c| c.contains("[workspace]"))
+    };
+
+    current.ancestors()
+        .find(|d| is_workspace(d))
+        .unwrap_or(&current)
+        .to_path_buf()
+}
+
+// without a part, all parts of the day are solved from a single parse.
+// Without an input, the cached input of the day is used (see `aoc fetch`).
+fn run(args: &[String]) -> ExitCode {
+    let (day, part, input) = match day_arguments(args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match days::solve(day, part, &input) {
+        Ok(results) => {
+            let mut status = ExitCode::SUCCESS;
+            for (p, result) in &results {
+                match result {
+                    Answer::NoSolution(reason) => {
+                        eprintln!("There is no solution for day {} part {}: {}", day, p, reason);
+                        status = ExitCode::FAILURE;
+                    },
+                    result if part.is_some() => println!("Result is {}", result),
+                    result => println!("Part {}: {}", p, result)
+                }
+            }
+            status
+        },
+        Err(SolveError::Parse(e)) => {
+            print_parse_error(&e, input.lines(), input.line_offset());
+            ExitCode::FAILURE
+        },
+        Err(SolveError::NoSolution) => {
+            match part {
+                Some(part) => eprintln!("There is no solution for day {} part {}", day, part),
+                None => eprintln!("There is no solution for day {}", day)
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// `<day> [<part>] [<input>]`, shared by `run` and `visualize`.
+// A second argument is the part if it's a number, the input otherwise.
+fn day_arguments(args: &[String]) -> Result<(u8, Option<u8>, Input), String> {
+    if args.is_empty() || args.len() > 3 {
+        return Err(String::from(USAGE));
+    }
+
+    let day = args[0].parse::<u8>().map_err(|_| format!("'{}' is not a valid day", args[0]))?;
+    let (part, path) = match args.len() {
+        1 => (None, None),
+        2 => match args[1].parse::<u8>() {
+            Ok(part) => (Some(part), None),
+            Err(_) => (None, Some(args[1].clone()))
+        },
+        _ => {
+            let part = args[1].parse::<u8>().map_err(|_| format!("'{}' is not a valid part", args[1]))?;
+            (Some(part), Some(args[2].clone()))
+        }
+    };
+
+    Ok((day, part, read_input(day, path)?))
+}
+
+// the given file, or the cached input of the day
+fn read_input(day: u8, path: Option<String>) -> Result<Input, String> {
+    let path = match path {
+        Some(path) => path,
+        None => {
+            let cached = Cache::workspace().path(day);
+            if !cached.exists() {
+                return Err(format!("There is no input for day {} at {}, get it with 'aoc fetch {}'", day, cached.display(), day));
+            }
+            cached.display().to_string()
+        }
+    };
+
+    Input::from_file(&path).map_err(|e| format!("Error: {}", e))
+}
+
+// the error, followed by the offending input line and a marker below the column
+// `line_offset` moves the line of the error from the (trimmed) lines to the file, see `Input::line_offset`
+fn print_parse_error(error: &ParseError, lines: &[String], line_offset: usize) {
+    let file_line = if error.line > 0 { error.line + line_offset } else { 0 };
+    eprintln!("Error: {}", error.clone().at_line(file_line));
+
+    if let Some(line) = error.line.checked_sub(1).and_then(|index| lines.get(index)) {
+        eprintln!("{:>5} | {}", file_line, line);
+        if error.column > 0 {
+            eprintln!("{:>5} | {}^", "", " ".repeat(error.column - 1));
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: aoc new <day>";
+
+// Creates the crate of a new day and registers it in the workspace and the runner.
+// An existing day is never touched.
+pub fn run(args: &[String]) -> ExitCode {
+    let day = match args {
+        [day] => match day.parse::<u8>() {
+            Ok(day) if (1..=99).contains(&day) => day,
+            _ => {
+                eprintln!("'{}' is not a valid day", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match create_day(&crate::workspace_root(), day) {
+        Ok(()) => {
+            println!("Created day-{:02}, put the example into day-{:02}/test-input", day, day);
+            ExitCode::SUCCESS
+        },
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn create_day(root: &Path, day: u8) -> Result<(), String> {
+    let name = format!("day-{:02}", day);
+    let directory = root.join(&name);
+    if directory.exists() {
+        return Err(format!("{} already exists", directory.display()));
+    }
+
+    // all edits are prepared first, so a failure doesn't leave a half registered day
+    let workspace = read(&root.join("Cargo.toml"))?;
+    let runner = read(&root.join("aoc/Cargo.toml"))?;
+    let days = read(&root.join("aoc/src/days.rs"))?;
+    let workspace = add_member(&workspace, &name)?;
+    let runner = add_dependency(&runner, &name)?;
+    let days = add_dispatch(&days, day)?;
+
+    write(&directory.join("Cargo.toml"), &cargo_toml(&name))?;
+    write(&directory.join("src/lib.rs"), LIB_TEMPLATE)?;
+    write(&directory.join("test-input"), "")?;
+    write(&root.join("Cargo.toml"), &workspace)?;
+    write(&root.join("aoc/Cargo.toml"), &runner)?;
+    write(&root.join("aoc/src/days.rs"), &days)?;
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("can't read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("can't create {}: {}", parent.display(), e))?;
+    }
+
+    fs::write(path, content).map_err(|e| format!("can't write {}: {}", path.display(), e))
+}
+
+// inserts `line` before the first entry (a line matching `is_entry`) which sorts after it,
+// or after the last entry
+fn insert_sorted(content: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|i| is_entry(lines[*i])).collect();
+    let last = *entries.last().ok_or(format!("no place found for '{}'", line.trim()))?;
+
+    let position = entries.iter()
+        .find(|i| lines[**i].trim() > line.trim())
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(position, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// the members of the root Cargo.toml
+fn add_member(content: &str, name: &str) -> Result<String, String> {
+    insert_sorted(content, &format!("    \"{}\",", name), |l| l.trim().starts_with("\"day-"))
+}
+
+// the dependencies of the runner
+fn add_dependency(content: &str, name: &str) -> Result<String, String> {
+    insert_sorted(content, &format!("{} = {{ path = \"../{}\" }}", name, name), |l| l.starts_with("day-"))
+}
+
+// the match arms of `dispatch!` in days.rs
+fn add_dispatch(content: &str, day: u8) -> Result<String, String> {
+    let arm = format!("            {} => $action!(day_{:02}, $($arg),+),", day, day);
+    let is_arm = |l: &str| l.contains("=> $action!(day_");
+
+    // the arms are sorted by number, not as text
+    let mut lines: Vec<&str> = content.lines().collect();
+    let arms: Vec<usize> = (0..lines.len()).filter(|i| is_arm(lines[*i])).collect();
+    let last = *arms.last().ok_or("no dispatch! in days.rs")?;
+    let number = |l: &str| l.trim().split(' ').next().and_then(|n| n.parse::<u8>().ok()).unwrap_or(0);
+
+    let position = arms.iter()
+        .find(|i| number(lines[**i]) > day)
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(position, &arm);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(r#"[package]
+name = "{}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common = {{ workspace = true }}
+
+[lints]
+workspace = true
+"#, name)
+}
+
+const LIB_TEMPLATE: &str = r#"use aoc_common::{Answer, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().clone())
+}
+
+pub fn part1(_lines: &Vec<String>) -> Answer {
+    Answer::from(0)
+}
+
+pub fn part2(_lines: &Vec<String>) -> Answer {
+    Answer::from(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 0); // the answer of the example
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 0); // the answer of the example
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registration() {
+        let workspace = "members = [\n    \"aoc\",\n    \"day-01\",\n    \"day-03\",\n]\n";
+        assert_eq!(add_member(workspace, "day-02").unwrap(), "members = [\n    \"aoc\",\n    \"day-01\",\n    \"day-02\",\n    \"day-03\",\n]\n");
+        assert_eq!(add_member(workspace, "day-04").unwrap(), "members = [\n    \"aoc\",\n    \"day-01\",\n    \"day-03\",\n    \"day-04\",\n]\n");
+
+        let runner = "[dependencies]\nday-01 = { path = \"../day-01\" }\n\n[lints]\n";
+        assert_eq!(add_dependency(runner, "day-02").unwrap(), "[dependencies]\nday-01 = { path = \"../day-01\" }\nday-02 = { path = \"../day-02\" }\n\n[lints]\n");
+
+        let days = "            9 => $action!(day_09, $($arg),+),\n            25 => $action!(day_25, $($arg),+; 1 => part1),\n            _ => return\n";
+        let lines: Vec<String> = add_dispatch(days, 10).unwrap().lines().map(String::from).collect();
+        assert_eq!(lines[1], "            10 => $action!(day_10, $($arg),+),");
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write(&root.join("Cargo.toml"), "members = [\n    \"day-01\",\n]\n").unwrap();
+        write(&root.join("aoc/Cargo.toml"), "day-01 = { path = \"../day-01\" }\n").unwrap();
+        write(&root.join("aoc/src/days.rs"), "            1 => $action!(day_01, $($arg),+),\n").unwrap();
+
+        create_day(&root, 2).unwrap();
+        assert!(root.join("day-02/test-input").exists());
+        assert!(read(&root.join("day-02/src/lib.rs")).unwrap().contains("fn part2"));
+        assert!(read(&root.join("aoc/src/days.rs")).unwrap().contains("2 => $action!(day_02"));
+
+        // an existing day is not overwritten
+        write(&root.join("day-02/src/lib.rs"), "solved").unwrap();
+        assert!(create_day(&root, 2).is_err());
+        assert_eq!(read(&root.join("day-02/src/lib.rs")).unwrap(), "solved");
+    }
+}
@@ -0,0 +1,257 @@
+use std::io;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use aoc_common::Simulation;
+use crate::days::{self, Simulations, SolveError};
+
+const CONTROLS: &str = "space play/pause  ←/→ step  PgUp/PgDn ±100  Home start  <n> Enter go to frame  +/- speed  q quit";
+
+// frames per second
+const DEFAULT_SPEED: u32 = 8;
+const MAX_SPEED: u32 = 8192;
+// faster speeds do several steps per redraw
+const MAX_REDRAWS_PER_SECOND: u32 = 30;
+const SEEK_DISTANCE: usize = 100;
+
+// Plays a simulation frame by frame. Simulations only go forward, going back
+// replays a fresh simulation up to the frame.
+struct Player {
+    simulations: Simulations,
+    simulation: Box<dyn Simulation>,
+    frame: usize,
+    ended: bool
+}
+
+impl Player {
+    fn new(simulations: Simulations) -> Self {
+        let simulation = simulations();
+
+        Player { simulations, simulation, frame: 0, ended: false }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.ended || !self.simulation.step() {
+            self.ended = true;
+            return false;
+        }
+        self.frame += 1;
+
+        true
+    }
+
+    // stops at the last frame if the simulation ends before `frame`
+    fn seek(&mut self, frame: usize) {
+        if frame < self.frame {
+            self.simulation = (self.simulations)();
+            self.frame = 0;
+            self.ended = false;
+        }
+
+        while self.frame < frame && self.step() {}
+    }
+}
+
+struct Controls {
+    playing: bool,
+    speed: u32,
+    // the frame number typed so far
+    target: String
+}
+
+pub fn run(args: &[String]) -> ExitCode {
+    let (day, part, input) = match crate::day_arguments(args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let simulations = match days::simulate(day, part, &input) {
+        Ok(simulations) => simulations,
+        Err(SolveError::Parse(e)) => {
+            crate::print_parse_error(&e, input.lines(), input.line_offset());
+            return ExitCode::FAILURE;
+        },
+        Err(SolveError::NoSolution) => {
+            eprintln!("There is no simulation for day {}, days 6, 14 and 15 can be visualized", day);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut terminal = ratatui::init();
+    let result = play(&mut terminal, &format!(" Day {} ", day), Player::new(simulations));
+    ratatui::restore();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn play(terminal: &mut DefaultTerminal, title: &str, mut player: Player) -> io::Result<()> {
+    let mut controls = Controls { playing: false, speed: DEFAULT_SPEED, target: String::new() };
+    let mut last_tick = Instant::now();
+
+    loop {
+        terminal.draw(|f| draw(f, title, &player, &controls))?;
+
+        let interval = Duration::from_secs(1) / controls.speed.min(MAX_REDRAWS_PER_SECOND);
+        let timeout = if controls.playing {
+            interval.saturating_sub(last_tick.elapsed())
+        } else {
+            Duration::from_secs(1)
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !handle(key.code, &mut player, &mut controls) {
+                    return Ok(());
+                }
+            }
+        }
+
+        if controls.playing && last_tick.elapsed() >= interval {
+            for _ in 0..controls.speed.div_ceil(MAX_REDRAWS_PER_SECOND) {
+                if !player.step() {
+                    controls.playing = false;
+                    break;
+                }
+            }
+            last_tick = Instant::now();
+        }
+    }
+}
+
+// false if the player should quit
+fn handle(key: KeyCode, player: &mut Player, controls: &mut Controls) -> bool {
+    match key {
+        KeyCode::Char('q') | KeyCode::Esc => return false,
+        KeyCode::Char(' ') => controls.playing = !controls.playing,
+        KeyCode::Right => {
+            controls.playing = false;
+            player.step();
+        },
+        KeyCode::Left => {
+            controls.playing = false;
+            player.seek(player.frame.saturating_sub(1));
+        },
+        KeyCode::PageDown => player.seek(player.frame + SEEK_DISTANCE),
+        KeyCode::PageUp => player.seek(player.frame.saturating_sub(SEEK_DISTANCE)),
+        KeyCode::Home => player.seek(0),
+        KeyCode::Char('+') | KeyCode::Up => controls.speed = (controls.speed * 2).min(MAX_SPEED),
+        KeyCode::Char('-') | KeyCode::Down => controls.speed = (controls.speed / 2).max(1),
+        KeyCode::Char(c) if c.is_ascii_digit() => controls.target.push(c),
+        KeyCode::Backspace => {
+            controls.target.pop();
+        },
+        KeyCode::Enter => {
+            if let Ok(frame) = controls.target.parse::<usize>() {
+                player.seek(frame);
+            }
+            controls.target.clear();
+        },
+        _ => {}
+    }
+
+    true
+}
+
+fn draw(f: &mut Frame, title: &str, player: &Player, controls: &Controls) {
+    let [map, status] = Layout::vertical([Constraint::Min(0), Constraint::Length(4)]).areas(f.area());
+
+    let grid = player.simulation.frame().to_string();
+    f.render_widget(Paragraph::new(grid).block(Block::bordered().title(title)), map);
+
+    let state = if controls.playing {
+        "playing"
+    } else if player.ended {
+        "ended"
+    } else {
+        "paused"
+    };
+    let target = if controls.target.is_empty() {
+        String::new()
+    } else {
+        format!(" | go to frame {}", controls.target)
+    };
+    let lines = vec![
+        Line::from(format!("frame {} | {} | {} at {} frames/s{}", player.frame, player.simulation.status(), state, controls.speed, target)),
+        Line::from(CONTROLS)
+    ];
+    f.render_widget(Paragraph::new(lines).block(Block::bordered()), status);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Grid;
+
+    // counts to 5
+    struct Counter {
+        count: i64
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            self.count += 1;
+            self.count <= 5
+        }
+
+        fn frame(&self) -> Grid<char> {
+            Grid::new(1, 1, '.')
+        }
+
+        fn status(&self) -> String {
+            self.count.to_string()
+        }
+    }
+
+    fn player() -> Player {
+        Player::new(Box::new(|| Box::new(Counter { count: 0 })))
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut player = player();
+        player.seek(3);
+        assert_eq!(player.frame, 3);
+
+        player.seek(10);
+        assert_eq!(player.frame, 5);
+        assert!(player.ended);
+        assert!(!player.step());
+
+        // going back starts over
+        player.seek(1);
+        assert_eq!(player.frame, 1);
+        assert_eq!(player.simulation.status(), "1");
+        assert!(player.step());
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut player = player();
+        let mut controls = Controls { playing: true, speed: DEFAULT_SPEED, target: String::new() };
+
+        handle(KeyCode::Char('4'), &mut player, &mut controls);
+        handle(KeyCode::Enter, &mut player, &mut controls);
+        assert_eq!(player.frame, 4);
+        assert!(controls.target.is_empty());
+
+        handle(KeyCode::Left, &mut player, &mut controls);
+        assert_eq!(player.frame, 3);
+        assert!(!controls.playing);
+
+        handle(KeyCode::Char('+'), &mut player, &mut controls);
+        assert_eq!(controls.speed, DEFAULT_SPEED * 2);
+        assert!(!handle(KeyCode::Char('q'), &mut player, &mut controls));
+    }
+}
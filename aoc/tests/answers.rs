@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use serde::Deserialize;
+use aoc_common::Answer;
+
+// Runs every answer of answers.toml through the `aoc` binary. Answers whose input
+// is not on this machine (the private puzzle inputs) are skipped.
+
+#[derive(Debug, Deserialize)]
+struct Answers {
+    #[serde(default)]
+    answer: Vec<Accepted>
+}
+
+#[derive(Debug, Deserialize)]
+struct Accepted {
+    day: u8,
+    part: u8,
+    input: String,
+    expected: Expected
+}
+
+// numbers are written as TOML integers, everything else (and numbers beyond i64) as strings
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Integer(i64),
+    Text(String)
+}
+
+impl From<&Expected> for Answer {
+    fn from(expected: &Expected) -> Self {
+        match expected {
+            Expected::Integer(n) => Answer::from(*n),
+            Expected::Text(s) => Answer::from(s.as_str())
+        }
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn solve(answer: &Accepted) -> Result<String, String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(workspace_root())
+        .args(["run", &answer.day.to_string(), &answer.part.to_string(), &answer.input])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let result = stdout.lines().find_map(|l| l.strip_prefix("Result is "));
+    match result {
+        Some(result) if output.status.success() => Ok(String::from(result)),
+        _ => Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+// the failures and the number of skipped answers
+fn check(answers: &Answers) -> (Vec<String>, usize) {
+    let mut failures: Vec<String> = vec![];
+    let mut skipped = 0;
+    for answer in &answers.answer {
+        if !workspace_root().join(&answer.input).exists() {
+            skipped += 1;
+            continue;
+        }
+
+        let name = format!("day {} part {} ({})", answer.day, answer.part, answer.input);
+        let expected = Answer::from(&answer.expected);
+        match solve(answer) {
+            Ok(actual) if expected == actual.as_str() => {},
+            Ok(actual) => failures.push(format!("{}: expected {}, actual {}", name, expected, actual)),
+            Err(error) => failures.push(format!("{}: expected {}, failed with '{}'", name, expected, error))
+        }
+    }
+
+    (failures, skipped)
+}
+
+#[test]
+fn test_answers() {
+    let content = fs::read_to_string(workspace_root().join("answers.toml")).unwrap();
+    let answers: Answers = toml::from_str(&content).unwrap();
+
+    let (failures, skipped) = check(&answers);
+    if skipped > 0 {
+        eprintln!("skipped {} answer(s) without an input file", skipped);
+    }
+    assert!(failures.is_empty(), "{} wrong answer(s):\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn test_missing_input_is_skipped() {
+    let answers: Answers = toml::from_str(r#"
+        [[answer]]
+        day = 1
+        part = 1
+        input = "day-01/test-input"
+        expected = 11
+
+        [[answer]]
+        day = 1
+        part = 2
+        input = "inputs/missing/day-01.txt"
+        expected = 0
+    "#).unwrap();
+
+    let (failures, skipped) = check(&answers);
+    assert!(failures.is_empty(), "{:?}", failures);
+    assert_eq!(skipped, 1);
+
+    // a wrong answer for an existing input still fails
+    let answers: Answers = toml::from_str(r#"
+        [[answer]]
+        day = 1
+        part = 1
+        input = "day-01/test-input"
+        expected = 12
+    "#).unwrap();
+    assert_eq!(check(&answers), (vec![String::from("day 1 part 1 (day-01/test-input): expected 12, actual 11")], 0));
+}
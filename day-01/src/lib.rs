@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::iter::zip;
+use aoc_common::{Answer, Input, ParseError};
+
+pub struct Lists {
+    left: Vec<i64>,
+    right: Vec<i64>
+}
+
+pub fn parse(input: &Input) -> Result<Lists, ParseError> {
+    let mut left: Vec<i64> = Vec::new();
+    let mut right: Vec<i64> = Vec::new();
+
+    for (index, numbers) in input.numbers::<i64>()?.iter().enumerate() {
+        if numbers.len() != 2 {
+            return Err(ParseError::new(0, "two numbers", &input.lines()[index]).at_line(index + 1));
+        }
+        left.push(numbers[0]);
+        right.push(numbers[1]);
+    }
+
+    Ok(Lists { left, right })
+}
+
+pub fn part1(lists: &Lists) -> Answer {
+    let mut left_list = lists.left.clone();
+    let mut right_list = lists.right.clone();
+
+    left_list.sort();
+    right_list.sort();
+
+    let result: u64 = zip(left_list, right_list)
+        .fold(0u64, |r, e| r + e.0.abs_diff(e.1));
+
+    Answer::from(result)
+}
+
+pub fn part2(lists: &Lists) -> Answer {
+    let occurrence = lists.right.iter()
+        .fold(HashMap::<i64, i64>::new(), |mut o, item| {
+            *o.entry(*item).or_default() += 1;
+            o
+        });
+
+    let result = lists.left.iter().fold(0i64, |r, left_num| {
+        let count = match occurrence.get_key_value(left_num) {
+            Some(c) => *c.1,
+            None => 0
+        };
+
+        r + left_num * count
+    });
+
+    Answer::from(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 31);
+    }
+}
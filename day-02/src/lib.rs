@@ -1,44 +1,22 @@
-use std::env;
-use std::fs;
 use itertools::Itertools;
+use aoc_common::{Answer, Input, ParseError};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Not enough command line arguments");
-    }
-    
-    let input = &args[1];
-    let lines = read_file(input);
-    let result = process(&lines);
-    
-    println!("Result is {}", result);
-}
+pub fn parse(input: &Input) -> Result<Vec<Vec<i64>>, ParseError> {
+    let reports = input.numbers()?;
 
-pub fn read_file(file_name: &String) -> Vec<String> {
-    let lines = fs::read_to_string(file_name)
-        .expect("Could not read file");
-
-    let lines: Vec<String> = lines
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect();
-    
-    lines
+    Ok(reports)
 }
 
-pub fn process(lines: &Vec<String>) -> usize {
-    let numbers: Vec<Vec<i64>> = lines
-        .iter().map(|l| {
-            let numbers: Vec<i64> = l.split(' ')
-                .map(|n| n.parse::<i64>().unwrap())
-                .collect();
+pub fn part1(reports: &Vec<Vec<i64>>) -> Answer {
+    let valid_lines = reports.iter().map(is_valid)
+        .filter(|r| *r)
+        .count();
 
-            numbers
-        }).collect();
+    Answer::from(valid_lines)
+}
 
-    let valid_lines = numbers.iter().map(|l| {
+pub fn part2(reports: &Vec<Vec<i64>>) -> Answer {
+    let valid_lines = reports.iter().map(|l| {
             (l, is_valid(l))
         })
         .map(|vl| {
@@ -51,7 +29,7 @@ pub fn process(lines: &Vec<String>) -> usize {
         .filter(|b| b == &true)
         .count();
 
-    valid_lines
+    Answer::from(valid_lines)
 }
 
 fn try_combinations(line: &Vec<i64>) -> bool {
@@ -72,9 +50,11 @@ fn try_combinations(line: &Vec<i64>) -> bool {
 }
 
 fn is_valid(nums: &Vec<i64>) -> bool {
-    let diffs: Vec<i64> = nums.windows(2).map(|t| {
-        t[1] - t[0]
-    }).collect();
+    let diffs: Vec<i64> = nums
+        .windows(2)
+        .map(|t| {
+            t[1] - t[0]
+        }).collect();
 
     // check if differences are between -3..-1 or 1..3
     let diffs_ok = diffs.iter().all(|d| {
@@ -94,8 +74,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part() {
-        let result = process(&read_file(&String::from("../test-input")));
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
 
         assert_eq!(result, 4);
     }
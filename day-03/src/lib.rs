@@ -0,0 +1,64 @@
+use regex::Regex;
+use aoc_common::{Answer, Input, ParseError};
+
+// the memory is one long string, line breaks have no meaning
+pub fn parse(input: &Input) -> Result<String, ParseError> {
+    Ok(input.lines().concat())
+}
+
+pub fn part1(memory: &String) -> Answer {
+    let re = Regex::new(r"mul\(([1-9][0-9]{0,2}),([1-9][0-9]{0,2})\)").unwrap();
+
+    let result: i64 = re.captures_iter(memory).fold(0, |r, c| {
+        let n_1 = c.get(1).unwrap().as_str().parse::<i64>().unwrap();
+        let n_2 = c.get(2).unwrap().as_str().parse::<i64>().unwrap();
+
+        r + n_1 * n_2
+    });
+
+    Answer::from(result)
+}
+
+pub fn part2(memory: &String) -> Answer {
+    let re = Regex::new(r"do\(\)|don't\(\)|mul\(([1-9][0-9]{0,2}),([1-9][0-9]{0,2})\)").unwrap();
+
+    let mut enabled = true;
+    let result  = re.captures_iter(memory).fold(0, |r, c| {
+        let mut intermediate_result = 0;
+
+        let command = c.get(0).unwrap().as_str();
+        if command.starts_with("don't") {
+            enabled = false;
+        } else if command.starts_with("do") {
+            enabled = true;
+        } else if command.starts_with("mul") && enabled {
+            let n_1 = c.get(1).unwrap().as_str().parse::<i64>().unwrap();
+            let n_2 = c.get(2).unwrap().as_str().parse::<i64>().unwrap();
+
+            intermediate_result = n_1 * n_2
+        }
+
+        r + intermediate_result
+    });
+
+    Answer::from(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input-part2").unwrap()).unwrap());
+
+        assert_eq!(result, 48);
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::grid::NEIGHBOURS_8;
+use aoc_common::{Answer, Coord, Grid, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Grid<char>, ParseError> {
+    let grid = input.grid()?;
+
+    Ok(grid)
+}
+
+pub fn part1(grid: &Grid<char>) -> Answer {
+    let result = grid.coords()
+        .fold(0, |r, init_coord| {
+            // read four characters into each of the eight directions
+            let xmas_matches = NEIGHBOURS_8
+                .iter().filter(|direction| {
+                    let word: String = grid.ray(init_coord, **direction).take(4).collect();
+
+                    word.eq("XMAS")
+                }).count();
+
+            r + xmas_matches
+        });
+
+    Answer::from(result)
+}
+
+pub fn part2(grid: &Grid<char>) -> Answer {
+    let result = grid.coords()
+        .fold(0, |r, init_coord| {
+            let w_1 = get_word(grid, &[
+                (init_coord.0 - 1, init_coord.1 - 1),
+                (init_coord.0, init_coord.1),
+                (init_coord.0 + 1, init_coord.1 + 1)
+            ]);
+            let w_2 = get_word(grid, &[
+                (init_coord.0 - 1, init_coord.1 + 1),
+                (init_coord.0, init_coord.1),
+                (init_coord.0 + 1, init_coord.1 - 1)
+            ]);
+
+            let mut hit = 0;
+            if (w_1.eq("MAS") || w_1.eq("SAM")) &&  (w_2.eq("MAS") || w_2.eq("SAM")) {
+                hit = 1;
+            }
+
+            r + hit
+        });
+
+    Answer::from(result)
+}
+
+fn get_word(grid: &Grid<char>, coords: &[Coord; 3]) -> String {
+    let mut s = String::new();
+
+    coords.iter().for_each(|c| s.push(*grid.get(*c).unwrap_or(&'.')));
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 9);
+    }
+}
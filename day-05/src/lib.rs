@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+pub struct Manual {
+    rules: OrderRules,
+    updates: Vec<PrintUpdate>
+}
+
+pub fn parse(input: &Input) -> Result<Manual, ParseError> {
+    let mut puzzle_components = input.numbered_sections().into_iter();
+
+    let (rules_line, ordering_rules) = puzzle_components.next()
+        .ok_or_else(|| ParseError::new(0, "page ordering rules", "").at_line(1))?;
+    let (updates_line, print_updates) = puzzle_components.next()
+        .ok_or_else(|| ParseError::new(0, "print updates", "").at_line(input.lines().len() + 1))?;
+
+    let rules = OrderRules::try_from(ordering_rules).map_err(|e| e.offset(rules_line))?;
+    let updates: Vec<PrintUpdate> = print_updates.iter().enumerate()
+        .map(|(index, line)| line.parse::<PrintUpdate>().map_err(|e| e.at_line(updates_line + index)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Manual { rules, updates })
+}
+
+pub fn part1(manual: &Manual) -> Answer {
+    let result: i64 = manual.updates.iter()
+        .filter(|pu| {
+            pu.is_order_valid(&manual.rules)
+        })
+        .map(|pu| {
+            pu.middle_page()
+        })
+        .sum();
+
+    Answer::from(result)
+}
+
+pub fn part2(manual: &Manual) -> Answer {
+    let result: i64 = manual.updates.iter()
+        .filter(|pu| {
+            !pu.is_order_valid(&manual.rules)
+        })
+        .map(|pu| {
+            pu.fix_order(&manual.rules)
+        })
+        .map(|pu| {
+            pu.middle_page()
+        })
+        .sum();
+
+    Answer::from(result)
+}
+
+struct OrderRules {
+    rules: HashMap<i64, Vec<i64>>
+}
+
+impl TryFrom<&[String]> for OrderRules {
+    type Error = ParseError;
+
+    fn try_from(lines: &[String]) -> Result<Self, Self::Error> {
+        let mut rules: HashMap<i64, Vec<i64>> = HashMap::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            // e.g.: 47|53
+            let rule = parse::split_once(line, "|", 1).and_then(|((before, before_column), (after, after_column))| {
+                Ok((parse::number::<i64>(before, before_column)?, parse::number::<i64>(after, after_column)?))
+            });
+            let (before, after) = rule.map_err(|e| e.at_line(index + 1))?;
+
+            rules.entry(before).or_default().push(after);
+        }
+
+        Ok(OrderRules { rules })
+    }
+}
+
+struct PrintUpdate {
+    pages: Vec<i64>
+}
+
+impl FromStr for PrintUpdate {
+    type Err = ParseError;
+
+    // e.g.: 75,47,61,53,29
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut pages: Vec<i64> = vec![];
+        let mut column = 1;
+        for token in line.split(',') {
+            pages.push(parse::number::<i64>(token, column)?);
+            column += token.len() + 1;
+        }
+
+        // only updates with a middle page make sense
+        if pages.len().is_multiple_of(2) {
+            return Err(ParseError::new(column - 1, "an odd number of pages", line));
+        }
+
+        Ok(PrintUpdate { pages })
+    }
+}
+
+impl PrintUpdate {
+    pub fn is_order_valid(&self, rules: &OrderRules) -> bool {
+        for (index, page) in self.pages.iter().enumerate() {
+            // update is invalid if any page which should come after
+            // current page is found before this page
+
+            if let Some(rule_set) = rules.rules.get(page) {
+                let prev_pages = self.pages.get(0..index).unwrap();
+
+                let order_violation = rule_set.iter().any(|after_page| {
+                   prev_pages.contains(after_page)
+                });
+                if order_violation {
+                    return false;
+                }
+            } // else: for current page, there is no rule set we can check
+        }
+
+        true
+    }
+
+    pub fn fix_order(&self, rules: &OrderRules) -> PrintUpdate {
+        let mut copy_update = PrintUpdate { pages: self.pages.clone() };
+
+        loop {
+            if copy_update.is_order_valid(rules) {
+                break;
+            }
+
+            // loop from the beginning, if there is a rule violation, swap items
+            'outer: for (index, page) in copy_update.pages.clone().iter().enumerate() {
+                if let Some(rule_set) = rules.rules.get(page) {
+                    let prev_pages = copy_update.pages.get(0..index).unwrap();
+                    for page_after in rule_set {
+                        if let Some(prev_pos) = prev_pages.iter().position(|item| item == page_after) {
+                            // swap prev_pos with index
+                            copy_update.pages.swap(index, prev_pos);
+                            break 'outer;
+                        }
+                    }
+                } // else: for current page, there is no rule set we can check
+            }
+        }
+
+        copy_update
+    }
+
+    pub fn middle_page(&self) -> i64 {
+        if self.pages.len().is_multiple_of(2) {
+            panic!("Page set has even number of pages. There is no middle page");
+        }
+
+        *self.pages.get(self.pages.len() / 2).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 143);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 123);
+    }
+}
@@ -1,53 +1,37 @@
 use std::collections::HashSet;
-use std::env;
-use std::fs;
 use crate::Walk::{HorizontalRange, VerticalRange};
+use aoc_common::{Answer, Grid, Input, ParseError, Simulation};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Not enough command line arguments");
-    }
-    
-    let input = &args[1];
-    let lines = read_file(input);
-    let result = process(&lines);
-    
-    println!("Result is {}", result);
+pub fn parse(input: &Input) -> Result<Map, ParseError> {
+    Map::try_from(&input.grid()?)
 }
 
-pub fn read_file(file_name: &String) -> Vec<String> {
-    let lines = fs::read_to_string(file_name)
-        .expect("Could not read file");
-
-    let lines: Vec<String> = lines
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect();
-    
-    lines
+pub fn part1(map: &Map) -> Answer {
+    let mut map = map.clone();
+    let (_, trace) = map.walk_till_end();
+    let distinct_positions = explode_positions(trace);
+
+    Answer::from(distinct_positions.len())
 }
 
-pub fn process(lines: &Vec<String>) -> usize {
-    let mut map = Map::from(lines);
+pub fn part2(map: &Map) -> Answer {
+    let mut initial_map = map.clone();
 
-    let initial_guard_pos = map.guard.0;
-    let (_, initial_trace) = map.walk_till_end();
+    let initial_guard_pos = initial_map.guard.0;
+    let (_, initial_trace) = initial_map.walk_till_end();
     let mut full_trace = explode_positions(initial_trace);
     full_trace.remove(&initial_guard_pos);
 
-    let working_copy = Map::from(lines);
     let mut loop_counter = 0;
     full_trace.iter().for_each(|p| {
-        let mut new_map = Map::from_map(&working_copy, *p);
+        let mut new_map = Map::from_map(map, *p);
         let (is_loop, _) = new_map.walk_till_end();
         if is_loop {
             loop_counter += 1;
         }
     });
 
-    loop_counter
+    Answer::from(loop_counter)
 }
 
 fn explode_positions(ranges: HashSet<Walk>) -> HashSet<Position> {
@@ -98,7 +82,7 @@ enum Walk {
     VerticalRange(u64, u64, u64)
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 enum Direction {
     Up,
     Down,
@@ -106,17 +90,21 @@ enum Direction {
     Right
 }
 
-impl Direction {
-    pub fn from(marker: &char) -> Self {
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(marker: char) -> Result<Self, Self::Error> {
         match marker {
-            '^' => Direction::Up,
-            'V' => Direction::Down,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            other => panic!("'{}' is not a valid direction marker", other)
+            '^' => Ok(Direction::Up),
+            'V' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            other => Err(ParseError::new(0, "a direction marker ('^', 'V', '<' or '>')", &other.to_string()))
         }
     }
+}
 
+impl Direction {
     pub fn next(&self) -> Self {
         match self {
             Direction::Up => Direction::Right,
@@ -125,10 +113,37 @@ impl Direction {
             Direction::Right => Direction::Down
         }
     }
+
+    fn marker(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'V',
+            Direction::Left => '<',
+            Direction::Right => '>'
+        }
+    }
 }
 
-#[derive(Debug)]
-struct Map {
+impl TryFrom<&Grid<char>> for Map {
+    type Error = ParseError;
+
+    fn try_from(map: &Grid<char>) -> Result<Self, Self::Error> {
+        let obstacles: HashSet<Position> = map.find_all(|c| *c == '#').iter()
+            .map(|(x, y)| (*x as u64, *y as u64))
+            .collect();
+        let guard_pos = map.find(|c| *c != '#' && *c != '.')
+            .ok_or_else(|| ParseError::new(0, "a guard", "").at_line(1))?;
+        let direction = Direction::try_from(map[guard_pos]).map_err(|e| e.at_cell(guard_pos))?;
+        let guard = ((guard_pos.0 as u64, guard_pos.1 as u64), direction);
+
+        let max_pos = (map.width() as u64 - 1, map.height() as u64 - 1);
+
+        Ok(Map { obstacles, guard, max_pos })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
     obstacles: HashSet<Position>,
     guard: (Position, Direction),
     max_pos: Position
@@ -138,46 +153,18 @@ impl Map {
     pub fn from_map(other: &Map, new_obs: Position) -> Self {
         let mut obstacles: HashSet<Position> = HashSet::new();
         other.obstacles.iter().for_each(|o| {
-            obstacles.insert(o.clone());
+            obstacles.insert(*o);
         });
         obstacles.insert(new_obs);
 
-        let guard = other.guard.clone();
-        let max_pos = other.max_pos.clone();
+        let guard = other.guard;
+        let max_pos = other.max_pos;
 
         Map { obstacles, guard, max_pos }
     }
 
-    pub fn from(map: &Vec<String>) -> Self {
-        let mut obstacles: HashSet<Position> = HashSet::new();
-        let mut guard: (Position, Direction) = ((0, 0), Direction::Right);
-
-        for (row, line) in map.iter().enumerate() {
-            for (column, c) in line.chars().enumerate() {
-                match c {
-                    '#' => {
-                        obstacles.insert((column as u64, row as u64));
-                    },
-                    '.' => {}, // ignore points
-                    other => {
-                        guard = ((column as u64, row as u64), Direction::from(&other));
-                    }
-                }
-            }
-        }
-
-        let max_x = obstacles.iter()
-            .max_by_key(|e| e.0)
-            .unwrap();
-        let max_y = obstacles.iter()
-            .max_by_key(|e| e.1)
-            .unwrap();
-        let max_pos = (max_x.0, max_y.1);
 
-        Map { obstacles, guard, max_pos }
-    }
-
-    pub fn walk_till_end(&mut self) -> (bool, HashSet<Walk>) {
+    fn walk_till_end(&mut self) -> (bool, HashSet<Walk>) {
         let mut ranges: HashSet<Walk> = HashSet::new();
 
         let is_loop = loop {
@@ -203,7 +190,7 @@ impl Map {
         (is_loop, ranges)
     }
 
-    pub fn walk(&mut self) -> Walk {
+    fn walk(&mut self) -> Walk {
         let old_pos = self.guard.0;
 
         let new_pos: Position = match self.guard.1 {
@@ -271,14 +258,113 @@ impl Map {
     }
 }
 
+// the walk of the guard cell by cell, for `aoc visualize`
+pub struct Patrol {
+    map: Map,
+    visited: HashSet<Position>,
+    states: HashSet<(Position, Direction)>,
+    steps: usize,
+    left_area: bool,
+    in_loop: bool
+}
+
+impl Patrol {
+    pub fn new(map: &Map) -> Self {
+        let visited = HashSet::from([map.guard.0]);
+        let states = HashSet::from([map.guard]);
+
+        Patrol { map: map.clone(), visited, states, steps: 0, left_area: false, in_loop: false }
+    }
+
+    // the cell in front of the guard, None if that's outside of the map
+    fn ahead(&self) -> Option<Position> {
+        let ((x, y), direction) = self.map.guard;
+        match direction {
+            Direction::Up => y.checked_sub(1).map(|y| (x, y)),
+            Direction::Down => Some((x, y + 1)).filter(|p| p.1 <= self.map.max_pos.1),
+            Direction::Left => x.checked_sub(1).map(|x| (x, y)),
+            Direction::Right => Some((x + 1, y)).filter(|p| p.0 <= self.map.max_pos.0)
+        }
+    }
+}
+
+impl Simulation for Patrol {
+    fn step(&mut self) -> bool {
+        if self.left_area || self.in_loop {
+            return false;
+        }
+
+        match self.ahead() {
+            None => self.left_area = true,
+            Some(p) if self.map.obstacles.contains(&p) => self.map.guard.1 = self.map.guard.1.next(),
+            Some(p) => {
+                self.map.guard.0 = p;
+                self.visited.insert(p);
+            }
+        }
+        self.in_loop = !self.left_area && !self.states.insert(self.map.guard);
+        self.steps += 1;
+
+        true
+    }
+
+    fn frame(&self) -> Grid<char> {
+        let (max_x, max_y) = self.map.max_pos;
+        let mut frame = Grid::new(max_x as usize + 1, max_y as usize + 1, '.');
+        self.map.obstacles.iter().for_each(|(x, y)| frame[(*x as i64, *y as i64)] = '#');
+        self.visited.iter().for_each(|(x, y)| frame[(*x as i64, *y as i64)] = 'X');
+        if !self.left_area {
+            let ((x, y), direction) = self.map.guard;
+            frame[(x as i64, y as i64)] = direction.marker();
+        }
+
+        frame
+    }
+
+    fn status(&self) -> String {
+        let state = if self.left_area {
+            ", the guard left the area"
+        } else if self.in_loop {
+            ", the guard walks in a loop"
+        } else {
+            ""
+        };
+
+        format!("step {}, {} distinct positions{}", self.steps, self.visited.len(), state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_part() {
-        let result = process(&read_file(&String::from("../test-input")));
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
 
         assert_eq!(result, 6);
     }
+
+    #[test]
+    fn test_patrol() {
+        let map = parse(&Input::from_file("test-input").unwrap()).unwrap();
+        let mut patrol = Patrol::new(&map);
+        while patrol.step() {}
+
+        assert_eq!(patrol.visited.len(), 41);
+        assert!(patrol.status().ends_with("left the area"));
+
+        // with an obstruction next to the starting position the guard walks in a loop
+        let mut patrol = Patrol::new(&Map::from_map(&map, (3, 6)));
+        while patrol.step() {}
+        assert!(patrol.in_loop);
+        assert_eq!(patrol.frame().find_char('^'), Some((4, 6)));
+    }
 }
@@ -0,0 +1,102 @@
+use std::str::FromStr;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Vec<Equation>, ParseError> {
+    input.parse_lines::<Equation>()
+}
+
+pub fn part1(equations: &Vec<Equation>) -> Answer {
+    let result: u64 = equations
+        .iter().filter(|e| e.is_valid(false))
+        .map(|e| e.lhs)
+        .sum();
+
+    Answer::from(result)
+}
+
+pub fn part2(equations: &Vec<Equation>) -> Answer {
+    let result: u64 = equations
+        .iter().filter(|e| e.is_valid(true))
+        .map(|e| e.lhs)
+        .sum();
+
+    Answer::from(result)
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    lhs: u64,
+    rhs: Vec<u64>
+}
+
+impl FromStr for Equation {
+    type Err = ParseError;
+
+    // e.g.: 3267: 81 40 27
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let ((lhs, lhs_column), (rhs, rhs_column)) = parse::split_once(line, ": ", 1)?;
+        let lhs = parse::number::<u64>(lhs, lhs_column)?;
+
+        let mut column = rhs_column;
+        let mut numbers: Vec<u64> = vec![];
+        for token in rhs.split(" ") {
+            numbers.push(parse::number::<u64>(token, column)?);
+            column += token.len() + 1;
+        }
+
+        Ok(Equation { lhs, rhs: numbers })
+    }
+}
+
+impl Equation {
+    pub fn is_valid(&self, with_concatenation: bool) -> bool {
+        let mut possible_results: Vec<u64> = vec![];
+        possible_results.push(*self.rhs.get(0).unwrap());
+
+        // combine each element (from left to right) with all possible
+        // previously found results
+        self.rhs.iter().skip(1).for_each(|e| {
+            let mut new_results: Vec<u64> = vec![];
+            possible_results.iter().for_each(|p_r| {
+                new_results.push(p_r + e);
+                new_results.push(p_r * e);
+
+                if with_concatenation {
+                    // thanks https://github.com/thekatze/advent-of-code/blob/main/src/year2024/day07.rs
+                    let s = p_r * 10u64.pow(e.ilog10() + 1) + e;
+                    new_results.push(s);
+                }
+            });
+            possible_results = new_results;
+        });
+
+        possible_results.contains(&self.lhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 3749);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 11387);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse(&Input::parse("190: 10 19\n3267: 81 4x 27")).unwrap_err();
+
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 10, "4x"));
+    }
+}
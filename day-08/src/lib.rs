@@ -1,71 +1,50 @@
 use std::collections::HashSet;
-use std::env;
-use std::fs;
 use itertools::Itertools;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
+use aoc_common::{Answer, Grid, Input, ParseError};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Not enough command line arguments");
-    }
-    
-    let input = &args[1];
-    let lines = read_file(input);
-    let result = process(&lines);
-    
-    println!("Result is {}", result);
+pub fn parse(input: &Input) -> Result<Map, ParseError> {
+    Map::try_from(&input.grid()?)
 }
 
-pub fn read_file(file_name: &String) -> Vec<String> {
-    let lines = fs::read_to_string(file_name)
-        .expect("Could not read file");
-
-    let lines: Vec<String> = lines
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect();
-    
-    lines
-}
+pub fn part1(map: &Map) -> Answer {
+    let antinodes = map.compute_antinodes(false);
 
-pub fn process(lines: &Vec<String>) -> usize {
-    let map = Map::from(lines);
+    Answer::from(antinodes.len())
+}
 
-    let antinodes = map.compute_antinodes();
+pub fn part2(map: &Map) -> Answer {
+    let antinodes = map.compute_antinodes(true);
 
-    antinodes.len()
+    Answer::from(antinodes.len())
 }
 
-struct Map {
+pub struct Map {
     antennas: HashSet<Antenna>,
-    max_location: (i64, i64)
+    grid: Grid<char>
 }
 
-impl Map {
-    pub fn from(lines: &Vec<String>) -> Self {
-        let mut antennas: HashSet<Antenna> = HashSet::new();
+impl TryFrom<&Grid<char>> for Map {
+    type Error = ParseError;
 
-        for (row, line) in lines.iter().enumerate() {
-            for (column, c) in line.chars().enumerate() {
-                if c == '.' {
-                    continue;
-                }
-
-                let antenna = Antenna::from(c, (column as i64, row as i64));
-                antennas.insert(antenna);
-            }
-        }
+    fn try_from(grid: &Grid<char>) -> Result<Self, Self::Error> {
+        let antennas: HashSet<Antenna> = grid.iter()
+            .filter(|(_, c)| **c != '.')
+            .map(|(location, c)| {
+                let antenna_type = AntennaType::try_from(*c).map_err(|e| e.at_cell(location))?;
+                Ok(Antenna::from(antenna_type, location))
+            })
+            .collect::<Result<_, ParseError>>()?;
 
-        let max_x = lines.get(0).unwrap().len() - 1;
-        let max_y = lines.len() - 1;
-
-        Map { antennas, max_location: (max_x as i64, max_y  as i64) }
+        Ok(Map { antennas, grid: grid.clone() })
     }
-    
-    pub fn compute_antinodes(&self) -> HashSet<(i64, i64)> {
+}
+
+impl Map {
+    // with resonant harmonics, antinodes occur at any multiple of the distance
+    // between two antennas (including the antennas themselves)
+    pub fn compute_antinodes(&self, resonant_harmonics: bool) -> HashSet<(i64, i64)> {
         let mut antinodes: HashSet<(i64, i64)> = HashSet::new();
 
         // loop over all antenna types
@@ -85,9 +64,26 @@ impl Map {
                     (
                         a1.location, a2.location, a1.distance(a2)
                     )
-                }).for_each(|(l1, _, d)| {
+                }).for_each(|(l1, l2, d)| {
                     // if distance is zero, l1 and l2 are the same spots
-                    if !(d.0 == 0 && d.1 == 0) {
+                    if d.0 == 0 && d.1 == 0 {
+                        return;
+                    }
+
+                    if !resonant_harmonics {
+                        let mut ans: Vec<(i64, i64)> = vec![];
+                        ans.push((l1.0 + d.0, l1.1 + d.1));
+                        ans.push((l1.0 - d.0, l1.1 - d.1));
+
+                        ans.push((l2.0 + d.0, l2.1 + d.1));
+                        ans.push((l2.0 - d.0, l2.1 - d.1));
+
+                        ans.iter().for_each(|an| {
+                            if self.is_in_map(an) && *an != l1 && *an != l2 {
+                                antinodes.insert(*an);
+                            }
+                        });
+                    } else {
                         // it's enough to expand it only from antenna, since
                         // the second one would produce the same pattern
                         let positions = self.expand_frequency(l1, d);
@@ -103,15 +99,7 @@ impl Map {
     }
 
     pub fn is_in_map(&self, a: &(i64, i64)) -> bool {
-        if a.0 < 0 || a.1 < 0 {
-            return false;
-        }
-
-        if a.0 > self.max_location.0 || a.1 > self.max_location.1 {
-            return false;
-        }
-
-        true
+        self.grid.in_bounds(*a)
     }
 
     fn expand_frequency(&self, antenna: (i64, i64), distance: (i64, i64)) -> Vec<(i64, i64)> {
@@ -159,8 +147,8 @@ struct Antenna {
 }
 
 impl Antenna {
-    pub fn from(c: char, pos: (i64, i64)) -> Self {
-        Antenna { antenna_type: AntennaType::from(c), location: pos }
+    pub fn from(antenna_type: AntennaType, pos: (i64, i64)) -> Self {
+        Antenna { antenna_type, location: pos }
     }
 
     pub fn distance(&self, other: &Antenna) -> (i64, i64) {
@@ -237,9 +225,11 @@ enum AntennaType {
     Type9
 }
 
-impl AntennaType {
-    pub fn from(pos: char) -> Self {
-        match pos {
+impl TryFrom<char> for AntennaType {
+    type Error = ParseError;
+
+    fn try_from(pos: char) -> Result<Self, Self::Error> {
+        let antenna_type = match pos {
             'A' => AntennaType::TypeA,
             'B' => AntennaType::TypeB,
             'C' => AntennaType::TypeC,
@@ -302,8 +292,10 @@ impl AntennaType {
             '7' => AntennaType::Type7,
             '8' => AntennaType::Type8,
             '9' => AntennaType::Type9,
-            x=> panic!("'{}' is an unsupported antenna type", x)
-        }
+            x => return Err(ParseError::new(0, "an antenna (letter or digit) or '.'", &x.to_string()))
+        };
+
+        Ok(antenna_type)
     }
 }
 
@@ -312,8 +304,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part() {
-        let result = process(&read_file(&String::from("../test-input")));
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
 
         assert_eq!(result, 34);
     }
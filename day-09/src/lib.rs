@@ -0,0 +1,139 @@
+use aoc_common::{Answer, Input, ParseError};
+
+// the dense format: alternating lengths of files and free space
+pub fn parse(input: &Input) -> Result<Vec<u64>, ParseError> {
+    let line = input.single_line()?;
+
+    line.chars().enumerate().map(|(index, c)| {
+        c.to_digit(10)
+            .map(|d| d as u64)
+            .ok_or_else(|| ParseError::new(index + 1, "a digit", &c.to_string()).at_line(1))
+    }).collect()
+}
+
+pub fn part1(disk_map: &Vec<u64>) -> Answer {
+    let mut empty_indicies: Vec<usize> = vec![];
+    let mut blocks: Vec<Block> = vec![];
+
+    let mut disk_index = 0;
+
+    for (input_index, amount) in disk_map.iter().enumerate() {
+        (0..*amount).for_each(|sub_index| {
+            let i = disk_index + sub_index;
+            let block_count = input_index / 2;
+            if input_index % 2 == 0 { // block data
+                blocks.push(Block { id: block_count as u64, disk_index: i as usize})
+            } else { // empty data
+                empty_indicies.push(i as usize);
+            }
+        });
+        disk_index += amount;
+    }
+
+    // zip empty blocks with reversed block list
+    // for empty block, replace the blocks disk_index
+    // stop process if empty block index exceeds the old index of the blocks
+    for (empty_index, block) in empty_indicies.iter().zip(blocks.iter_mut().rev()) {
+        if empty_index > &block.disk_index {
+            break;
+        }
+
+        block.disk_index = *empty_index;
+    }
+
+    Answer::from(compute_checksum(&blocks))
+}
+
+pub fn part2(disk_map: &Vec<u64>) -> Answer {
+    let mut empty_indicies: Vec<(usize, u64)> = vec![];
+    let mut files: Vec<File> = vec![];
+
+    let mut disk_index = 0;
+
+    for (input_index, amount) in disk_map.iter().enumerate() {
+        if input_index % 2 == 0 { // block data
+            let block_count = input_index / 2;
+            files.push(File { id: block_count as u64, disk_index: (disk_index, *amount)})
+        } else { // empty data
+            empty_indicies.push((disk_index, *amount));
+        }
+
+        disk_index += *amount as usize;
+    }
+
+    defragment(&mut files, &mut empty_indicies);
+
+    Answer::from(compute_file_checksum(&files))
+}
+
+fn defragment(data_blocks: &mut Vec<File>, empty_blocks: &mut Vec<(usize, u64)>) {
+    // idea: start from last block, find first big enough block in empty_blocks
+    // update data_block start index
+    // remove/split empty_block from list
+
+    for block in data_blocks.iter_mut().rev() {
+        let empty_pos = empty_blocks.iter().position(|&e| {
+            e.0 < block.disk_index.0 && e.1 >= block.disk_index.1
+        });
+        if let Some(empty_pos) = empty_pos {
+            let empty_block = empty_blocks[empty_pos];
+            block.disk_index.0 = empty_block.0;
+            // split block
+            if empty_block.1 == block.disk_index.1 { // case 1: block fits completely
+                empty_blocks.remove(empty_pos);
+            } else { // empty block is larger than data block.
+                let new_empty_block = (empty_block.0 + block.disk_index.1 as usize, empty_block.1 - block.disk_index.1);
+                empty_blocks[empty_pos] = new_empty_block;
+            }
+        }
+    }
+}
+
+fn compute_checksum(blocks: &Vec<Block>) -> u64 {
+    blocks.iter().fold(0, |a, b| {
+        a + b.id * b.disk_index as u64
+    })
+}
+
+fn compute_file_checksum(files: &Vec<File>) -> u64 {
+    files.iter().fold(0, |a, b| {
+        let mut block_check_sum = 0;
+        for offset in 0..b.disk_index.1 {
+            block_check_sum += b.id * (b.disk_index.0 as u64 + offset);
+        }
+
+        a + block_check_sum
+    })
+}
+
+// a single block of a file
+struct Block {
+    id: u64,
+    disk_index: usize
+}
+
+// a whole file, which is only moved as one piece
+#[derive(Debug)]
+struct File {
+    id: u64,
+    disk_index: (usize, u64) // start index, number of elements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 2858);
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use aoc_common::{Answer, Coord, Grid, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Map, ParseError> {
+    Map::try_from(input)
+}
+
+pub fn part1(map: &Map) -> Answer {
+    Answer::from(map.score())
+}
+
+pub fn part2(map: &Map) -> Answer {
+    Answer::from(map.rating())
+}
+
+pub struct Map {
+    heights: Grid<i64>
+}
+
+impl TryFrom<&Input> for Map {
+    type Error = ParseError;
+
+    fn try_from(input: &Input) -> Result<Self, Self::Error> {
+        let heights = input.grid()?.try_map(|c| {
+            c.to_digit(10)
+                .map(|d| d as i64)
+                .ok_or_else(|| ParseError::new(0, "a height (digit)", &c.to_string()))
+        })?;
+
+        Ok(Map { heights })
+    }
+}
+
+impl Map {
+    // number of distinct peaks reachable from each trailhead
+    pub fn score(&self) -> usize {
+        let starting_points = self.heights.find_all(|h| *h == 0);
+
+        starting_points.iter().fold(0, |r, p| {
+            let mut points_seen: HashSet<Coord> = HashSet::new();
+            let distinct_paths = self.peaks_from_point(*p, &mut points_seen);
+
+            r + distinct_paths
+        })
+    }
+
+    // number of distinct trails from each trailhead
+    pub fn rating(&self) -> usize {
+        let starting_points = self.heights.find_all(|h| *h == 0);
+
+        starting_points.iter().fold(0, |r, p| {
+            let distinct_paths = self.trails_from_point(*p);
+
+            r + distinct_paths
+        })
+    }
+
+    fn peaks_from_point(&self, from: Coord, seen_points: &mut HashSet<Coord>) -> usize {
+        seen_points.insert(from);
+        let height = self.heights[from];
+        if height == 9 {
+            return 1;
+        }
+
+        let new_neighbours: Vec<Coord> = self.heights.neighbours4(from).filter(|p| {
+            self.heights[*p] == height + 1 && !seen_points.contains(p)
+        }).collect();
+
+        let mut distinct_paths = 0;
+        new_neighbours.iter().for_each(|p| {
+            // a neighbour could have been reached by an earlier branch in the meantime
+            if !seen_points.contains(p) {
+                distinct_paths += self.peaks_from_point(*p, seen_points)
+            }
+        });
+
+        distinct_paths
+    }
+
+    fn trails_from_point(&self, from: Coord) -> usize {
+        let height = self.heights[from];
+        if height == 9 {
+            return 1;
+        }
+
+        let mut distinct_paths = 0;
+        self.heights.neighbours4(from).for_each(|p| {
+            if self.heights[p] == height + 1 {
+                distinct_paths += self.trails_from_point(p)
+            }
+        });
+
+        distinct_paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 81);
+    }
+}
@@ -1,46 +1,28 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
+use aoc_common::{Answer, Input, ParseError};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Not enough command line arguments");
-    }
-    
-    let input = &args[1];
-    let lines = read_file(input);
-    let result = process(&lines);
-    
-    println!("Result is {}", result);
+pub fn parse(input: &Input) -> Result<Vec<u64>, ParseError> {
+    let numbers = input.numbers()?.concat();
+
+    Ok(numbers)
 }
 
-pub fn read_file(file_name: &String) -> Vec<String> {
-    let lines = fs::read_to_string(file_name)
-        .expect("Could not read file");
-
-    let lines: Vec<String> = lines
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect();
-    
-    lines
+pub fn part1(numbers: &Vec<u64>) -> Answer {
+    Answer::from(count_stones(numbers, 25))
 }
 
-pub fn process(lines: &Vec<String>) -> usize {
-    let numbers: Vec<u64> = lines.get(0).unwrap()
-        .split(" ")
-        .map(|n| n.parse::<u64>().unwrap())
-        .collect();
+pub fn part2(numbers: &Vec<u64>) -> Answer {
+    Answer::from(count_stones(numbers, 75))
+}
 
+fn count_stones(numbers: &Vec<u64>, blinks: usize) -> usize {
     // do not simulate the stones. Just count how often each stone appeared
     let mut number_counter: HashMap<u64, u64> = HashMap::new();
     numbers.iter().for_each(|n| { number_counter.insert(*n, 1); });
 
     let mut loop_counter = 0;
     loop {
-        if loop_counter == 75 {
+        if loop_counter == blinks {
             break;
         }
 
@@ -104,8 +86,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part() {
-        let result = process(&read_file(&String::from("../test-input")));
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 55312);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
 
         assert_eq!(result, 65601038650482); // no test value was supplied, but maybe I compute it myself
     }
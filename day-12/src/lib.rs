@@ -0,0 +1,356 @@
+use std::collections::{HashSet, VecDeque};
+use itertools::Itertools;
+use itertools::MinMaxResult::{OneElement, MinMax};
+use aoc_common::{Answer, Coord, Grid, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Garden, ParseError> {
+    Garden::try_from(input)
+}
+
+pub fn part1(garden: &Garden) -> Answer {
+    Answer::from(garden.fields.iter().fold(0, |r, f| {
+        let perimeter = compute_perimeter(f, &garden.map);
+        r + perimeter * f.len()
+    }))
+}
+
+pub fn part2(garden: &Garden) -> Answer {
+    Answer::from(garden.fields.iter().fold(0, |r, f| {
+        let segments = compute_segments(f, &garden.map);
+        r + segments * f.len()
+    }))
+}
+
+pub struct Garden {
+    map: Grid<PlantType>,
+    fields: Vec<HashSet<Coord>>
+}
+
+impl TryFrom<&Input> for Garden {
+    type Error = ParseError;
+
+    fn try_from(input: &Input) -> Result<Self, Self::Error> {
+        let map: Grid<PlantType> = input.grid()?.try_map(|c| PlantType::try_from(*c))?;
+
+        // idea: try each item in map, start a flood fill from there
+        // within flood fill: find each neighbouring plant with same type
+        // return list of neighboured plants
+        let mut available_plants: HashSet<Coord> = map.coords().collect();
+        let mut fields: Vec<HashSet<Coord>> = vec![];
+        loop {
+            if available_plants.is_empty() {
+                break;
+            }
+            let next_start = available_plants.iter().next().unwrap();
+            let field = flood_fill(*next_start, &map);
+            field.iter().for_each(|p| {
+                available_plants.remove(p);
+            });
+            fields.push(field);
+        }
+
+        Ok(Garden { map, fields })
+    }
+}
+
+// for calculation: for each group of plants: loop through each plant
+// number of fences is the number of neighbours (in global map) with a different plant type
+fn compute_perimeter(f: &HashSet<Coord>, map: &Grid<PlantType>) -> usize {
+    f.iter().fold(0, |r, p| {
+        let neighbours: Vec<Coord> = map.neighbours4(*p).collect();
+        let border_count = neighbours.iter().fold(0, |r, n| {
+            if map[*n] != map[*p] {
+                r + 1
+            } else {
+                r
+            }
+        });
+
+        // if neighbours is less than 4, it means we have a border field.
+        // each border field has a fence by definition
+
+        r + border_count + (4 - neighbours.len())
+    })
+}
+
+// for segment calculation, find continuous segments (top, bottom, left, right separately)
+// by going row/column wise through all points
+fn compute_segments(field: &HashSet<Coord>, map: &Grid<PlantType>) -> usize {
+    let (top_segments, bottom_segments) = compute_top_bottom_segments(field, map);
+    let (left_segments, right_segments) = compute_left_right_segments(field, map);
+
+    top_segments + bottom_segments + left_segments + right_segments
+}
+
+// a top/bottom segment is a plant, where the top neighbour has a different PlantType Or
+// if the plant is at a border
+fn compute_top_bottom_segments(field: &HashSet<Coord>, map: &Grid<PlantType>) -> (usize, usize) {
+    let minmax = field.iter().minmax_by_key(|p| p.1);
+    let (min_row, max_row) = match minmax {
+        OneElement(p) => (p.1, p.1),
+        MinMax(p_min, p_max) => (p_min.1, p_max.1),
+        _ => panic!("No minmax element found")
+    };
+
+    let mut total_top_segments = 0;
+    let mut total_bottom_segments = 0;
+    (min_row..=max_row).for_each(|row| {
+        let plants = get_plants_by_row(row, field);
+        let top_plants: Vec<&Coord> = plants.iter().copied().filter(|p| is_plant_top_element(p, map)).collect();
+        let bottom_plants: Vec<&Coord> = plants.iter().copied().filter(|p| is_plant_bottom_element(p, map)).collect();
+
+        let top_x_values = get_ordered_x_values(&top_plants);
+        total_top_segments += count_segments(&top_x_values);
+
+        let bottom_x_values = get_ordered_x_values(&bottom_plants);
+        total_bottom_segments += count_segments(&bottom_x_values);
+    });
+
+    (total_top_segments, total_bottom_segments)
+}
+
+fn compute_left_right_segments(field: &HashSet<Coord>, map: &Grid<PlantType>) -> (usize, usize) {
+    let minmax = field.iter().minmax_by_key(|p| p.0);
+    let (min_column, max_column) = match minmax {
+        OneElement(p) => (p.0, p.0),
+        MinMax(p_min, p_max) => (p_min.0, p_max.0),
+        _ => panic!("No minmax element found")
+    };
+
+    let mut total_left_segments = 0;
+    let mut total_right_segments = 0;
+    (min_column..=max_column).for_each(|row| {
+        let plants = get_plants_by_column(row, field);
+        let left_plants: Vec<&Coord> = plants.iter().copied().filter(|p| is_plant_left_element(p, map)).collect();
+        let right_plants: Vec<&Coord> = plants.iter().copied().filter(|p| is_plant_right_element(p, map)).collect();
+
+        let left_y_values = get_ordered_y_values(&left_plants);
+        total_left_segments += count_segments(&left_y_values);
+
+        let right_y_values = get_ordered_y_values(&right_plants);
+        total_right_segments += count_segments(&right_y_values);
+    });
+
+    (total_left_segments, total_right_segments)
+}
+
+fn count_segments(numbers: &Vec<i64>) -> usize {
+    if numbers.len() == 0 {
+        return 0;
+    }
+
+    let mut segment_counter = 1usize;
+
+    // we have a list of ids. Count the number of increasing segments
+    // e.g. 1 2 3 5 6 8
+    // has 3 segments. 1-3, 5-6 and 8
+    numbers.windows(2)
+        .for_each(|n| {
+           if n[1] > n[0] + 1 {
+               segment_counter += 1;
+           }
+        });
+
+    segment_counter
+}
+
+fn get_ordered_x_values(plants: &Vec<&Coord>) -> Vec<i64> {
+    plants.iter()
+        .map(|p| p.0)
+        .sorted()
+        .collect()
+}
+
+fn get_ordered_y_values(plants: &Vec<&Coord>) -> Vec<i64> {
+    plants.iter()
+        .map(|p| p.1)
+        .sorted()
+        .collect()
+}
+
+fn is_plant_left_element(plant: &Coord, map: &Grid<PlantType>) -> bool {
+    let left_neighbour = map.get((plant.0 - 1, plant.1));
+    match left_neighbour {
+        Some(n) => *n != map[*plant],
+        None => true
+    }
+}
+
+fn is_plant_right_element(plant: &Coord, map: &Grid<PlantType>) -> bool {
+    let left_neighbour = map.get((plant.0 + 1, plant.1));
+    match left_neighbour {
+        Some(n) => *n != map[*plant],
+        None => true
+    }
+}
+
+fn is_plant_top_element(plant: &Coord, map: &Grid<PlantType>) -> bool {
+    let top_neighbour = map.get((plant.0, plant.1 - 1));
+    match top_neighbour {
+        Some(n) => *n != map[*plant],
+        None => true
+    }
+}
+
+fn is_plant_bottom_element(plant: &Coord, map: &Grid<PlantType>) -> bool {
+    let top_neighbour = map.get((plant.0, plant.1 + 1));
+    match top_neighbour {
+        Some(n) => *n != map[*plant],
+        None => true
+    }
+}
+
+fn get_plants_by_row(row: i64, field: &HashSet<Coord>) -> Vec<&Coord> {
+    let row: Vec<&Coord> = field.iter().filter(|p| {
+       p.1 ==  row
+    }).collect();
+
+    row
+}
+
+fn get_plants_by_column(column: i64, field: &HashSet<Coord>) -> Vec<&Coord> {
+    let column: Vec<&Coord> = field.iter().filter(|p| {
+        p.0 ==  column
+    }).collect();
+
+    column
+}
+
+fn flood_fill(start: Coord, map: &Grid<PlantType>) -> HashSet<Coord> {
+    let mut field: HashSet<Coord> = HashSet::new();
+    let mut item_stack: VecDeque<Coord> = VecDeque::new();
+    item_stack.push_front(start);
+
+    loop {
+        if item_stack.is_empty() {
+            break;
+        }
+        let current_item = item_stack.pop_front().unwrap();
+        field.insert(current_item);
+
+        map.neighbours4(current_item).for_each(|n| {
+            // we are only interested in unseen neighbours of same type
+            if map[n] == map[current_item] && !field.contains(&n) {
+                field.insert(n);
+                item_stack.push_front(n);
+            }
+        });
+    }
+
+    field
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum PlantType {
+    TypeA,
+    TypeB,
+    TypeC,
+    TypeD,
+    TypeE,
+    TypeF,
+    TypeG,
+    TypeH,
+    TypeI,
+    TypeN,
+    TypeJ,
+    TypeK,
+    TypeL,
+    TypeM,
+    TypeO,
+    TypeP,
+    TypeQ,
+    TypeR,
+    TypeS,
+    TypeT,
+    TypeU,
+    TypeV,
+    TypeW,
+    TypeX,
+    TypeY,
+    TypeZ
+}
+
+impl TryFrom<char> for PlantType {
+    type Error = ParseError;
+
+    fn try_from(pos: char) -> Result<Self, Self::Error> {
+        let plant_type = match pos {
+            'A' => PlantType::TypeA,
+            'B' => PlantType::TypeB,
+            'C' => PlantType::TypeC,
+            'D' => PlantType::TypeD,
+            'E' => PlantType::TypeE,
+            'F' => PlantType::TypeF,
+            'G' => PlantType::TypeG,
+            'H' => PlantType::TypeH,
+            'I' => PlantType::TypeI,
+            'J' => PlantType::TypeJ,
+            'K' => PlantType::TypeK,
+            'L' => PlantType::TypeL,
+            'M' => PlantType::TypeM,
+            'N' => PlantType::TypeN,
+            'O' => PlantType::TypeO,
+            'P' => PlantType::TypeP,
+            'Q' => PlantType::TypeQ,
+            'R' => PlantType::TypeR,
+            'S' => PlantType::TypeS,
+            'T' => PlantType::TypeT,
+            'U' => PlantType::TypeU,
+            'V' => PlantType::TypeV,
+            'W' => PlantType::TypeW,
+            'X' => PlantType::TypeX,
+            'Y' => PlantType::TypeY,
+            'Z' => PlantType::TypeZ,
+            x => return Err(ParseError::new(0, "a plant type (capital letter)", &x.to_string()))
+        };
+
+        Ok(plant_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_file1() {
+        let result = part1(&parse(&Input::from_file("test-input-1").unwrap()).unwrap());
+        assert_eq!(result, 1930);
+    }
+
+    #[test]
+    fn test_part1_file2() {
+        let result = part1(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+        assert_eq!(result, 772);
+    }
+
+    #[test]
+    fn test_part1_file3() {
+        let result = part1(&parse(&Input::from_file("test-input-3").unwrap()).unwrap());
+        assert_eq!(result, 140);
+    }
+
+    #[test]
+    fn test_part2_file1() {
+        let result = part2(&parse(&Input::from_file("test-input-1").unwrap()).unwrap());
+        assert_eq!(result, 1206);
+    }
+
+    #[test]
+    fn test_part2_file2() {
+        let result = part2(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+        assert_eq!(result, 436);
+    }
+
+    #[test]
+    fn test_part2_file3() {
+        let result = part2(&parse(&Input::from_file("test-input-3").unwrap()).unwrap());
+        assert_eq!(result, 80);
+    }
+
+    #[test]
+    fn test_part2_file4() {
+        let result = part2(&parse(&Input::from_file("test-input-4").unwrap()).unwrap());
+        assert_eq!(result, 368);
+    }
+}
@@ -0,0 +1,117 @@
+use regex::Regex;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+// the prize positions in part 2 are shifted by this amount on both axes
+const PRIZE_OFFSET: i64 = 10000000000000;
+
+pub fn parse(input: &Input) -> Result<Vec<ClawMachine>, ParseError> {
+    input.numbered_sections().into_iter()
+        .map(|(first_line, section)| ClawMachine::try_from(section).map_err(|e| e.offset(first_line)))
+        .collect()
+}
+
+pub fn part1(claw_machines: &Vec<ClawMachine>) -> Answer {
+    Answer::from(total_costs(claw_machines.iter().cloned()))
+}
+
+pub fn part2(claw_machines: &Vec<ClawMachine>) -> Answer {
+    Answer::from(total_costs(claw_machines.iter().map(|c| c.with_offset(PRIZE_OFFSET))))
+}
+
+fn total_costs(claw_machines: impl Iterator<Item = ClawMachine>) -> i64 {
+    claw_machines
+        .map(|c| c.min_costs())
+        .filter(|r| r.0)
+        .map(|r| r.1 )
+        .sum()
+}
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    target_x: i64,
+    target_y: i64,
+    button_a_x_factor: i64,
+    button_a_y_factor: i64,
+    button_b_x_factor: i64,
+    button_b_y_factor: i64
+}
+
+// lines are counted from the start of the section
+impl TryFrom<&[String]> for ClawMachine {
+    type Error = ParseError;
+
+    fn try_from(input: &[String]) -> Result<Self, Self::Error> {
+        if input.len() != 3 {
+            let found = format!("{} lines", input.len());
+            return Err(ParseError::new(0, "two buttons and a prize (3 lines)", &found).at_line(input.len().min(3) + 1));
+        }
+
+        // e.g.: Button A: X+94, Y+34
+        let button_a_regex = Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap();
+        let button_b_regex = Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap();
+        // e.g.: Prize: X=12748, Y=12176
+        let target_regex = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+
+        let button_a = parse_numbers(&button_a_regex, &input[0], "'Button A: X+<n>, Y+<n>'").map_err(|e| e.at_line(1))?;
+        let button_b = parse_numbers(&button_b_regex, &input[1], "'Button B: X+<n>, Y+<n>'").map_err(|e| e.at_line(2))?;
+        let prize = parse_numbers(&target_regex, &input[2], "'Prize: X=<n>, Y=<n>'").map_err(|e| e.at_line(3))?;
+
+        Ok(ClawMachine {
+            target_x: prize[0],
+            target_y: prize[1],
+            button_a_x_factor: button_a[0],
+            button_a_y_factor: button_a[1],
+            button_b_x_factor: button_b[0],
+            button_b_y_factor: button_b[1]
+        })
+    }
+}
+
+// the numbers of all groups of `re`, which has to match the whole line
+fn parse_numbers(re: &Regex, line: &str, expected: &str) -> Result<Vec<i64>, ParseError> {
+    let captures = re.captures(line).ok_or_else(|| ParseError::new(1, expected, line))?;
+
+    captures.iter().skip(1).flatten()
+        .map(|m| parse::number::<i64>(m.as_str(), m.start() + 1))
+        .collect()
+}
+
+impl ClawMachine {
+    pub fn with_offset(&self, offset: i64) -> Self {
+        ClawMachine { target_x: self.target_x + offset, target_y: self.target_y + offset, ..self.clone() }
+    }
+
+    pub fn min_costs(&self) -> (bool, i64) {
+        let a_dividend = (self.target_x * self.button_b_y_factor - self.button_b_x_factor * self.target_y) as f64;
+        let a_divisor = (self.button_a_x_factor * self.button_b_y_factor - self.button_b_x_factor * self.button_a_y_factor) as f64;
+        let a = a_dividend / a_divisor;
+
+        if a - a.floor() != 0f64 { // is this the proper way of checking a for being an integer?
+            return (false, 0);
+        }
+        let b = (self.target_y as f64 - self.button_a_y_factor as f64 * a) / self.button_b_y_factor as f64;
+        let r = (true, 3*a as i64 + b as i64);
+
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 480);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 875318608908);
+    }
+}
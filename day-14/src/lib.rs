@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+use std::ops::Range;
+use std::str::FromStr;
+use regex::Regex;
+use aoc_common::parse;
+use aoc_common::{Answer, Grid, Input, ParseError, Simulation};
+
+pub fn parse(input: &Input) -> Result<Map, ParseError> {
+    parse_with_grid(input, (101, 103))
+}
+
+pub fn parse_with_grid(input: &Input, grid_size: (i64, i64)) -> Result<Map, ParseError> {
+    let robots: Vec<Robot> = input.parse_lines::<Robot>()?;
+
+    Ok(Map { max_x: grid_size.0, max_y: grid_size.1, robots })
+}
+
+pub fn part1(map: &Map) -> Answer {
+    let mut map = map.clone();
+
+    map.simulate_steps(100);
+    Answer::from(map.safety_factor())
+}
+
+// the robots arrange into a christmas tree in the first second in which no two of them
+// share a position (watch it with `aoc visualize 14`). After max_x * max_y seconds
+// all robots are back at their start, so there is no such second if none came up until then.
+pub fn part2(map: &Map) -> Answer {
+    let mut map = map.clone();
+
+    for seconds in 1..=map.max_x * map.max_y {
+        map.simulate_steps(1);
+        if map.robots_apart() {
+            return Answer::from(seconds);
+        }
+    }
+
+    Answer::NoSolution(String::from("the robots always share a position"))
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    max_x: i64,
+    max_y: i64,
+    robots: Vec<Robot>
+}
+
+impl Map {
+    pub fn simulate_steps(&mut self, steps: i64) {
+        self.robots.iter_mut().for_each(|r| {
+           r.move_in_grid(steps, self.max_x, self.max_y);
+        });
+    }
+
+    pub fn safety_factor(&self) -> usize {
+        let q1 = (0..self.max_x/2 - 1, 0..self.max_y/2 - 1);
+        let q2 = (self.max_x/2 + 1..self.max_x - 1, 0..self.max_y/2 - 1);
+        let q3 = (0..self.max_x/2 - 1, self.max_y/2 + 1..self.max_y - 1);
+        let q4 = (self.max_x/2 + 1..self.max_x - 1, self.max_y/2 + 1..self.max_y - 1);
+
+        let q1_r = self.count_robots(q1);
+        let q2_r = self.count_robots(q2);
+        let q3_r = self.count_robots(q3);
+        let q4_r = self.count_robots(q4);
+
+        q1_r * q2_r * q3_r * q4_r
+    }
+
+    pub fn robots_apart(&self) -> bool {
+        let mut positions: HashSet<(i64, i64)> = HashSet::new();
+
+        self.robots.iter().all(|r| positions.insert((r.x, r.y)))
+    }
+
+    pub fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.max_x as usize, self.max_y as usize, ' ');
+        self.robots.iter().for_each(|r| grid[(r.x, r.y)] = '#');
+
+        grid
+    }
+
+    fn count_robots(&self, range: (Range<i64>, Range<i64>)) -> usize {
+        self.robots.iter().filter(|r| {
+                let r = r.x >= range.0.start && r.x <= range.0.end
+                    && r.y >= range.1.start && r.y <= range.1.end;
+                r
+            })
+            .count()
+    }
+}
+
+// the robots second by second, for `aoc visualize`
+pub struct Motion {
+    map: Map,
+    seconds: usize
+}
+
+impl Motion {
+    pub fn new(map: &Map) -> Self {
+        Motion { map: map.clone(), seconds: 0 }
+    }
+}
+
+// the robots never stop, their positions repeat after max_x * max_y seconds
+impl Simulation for Motion {
+    fn step(&mut self) -> bool {
+        self.map.simulate_steps(1);
+        self.seconds += 1;
+
+        true
+    }
+
+    fn frame(&self) -> Grid<char> {
+        self.map.render()
+    }
+
+    fn status(&self) -> String {
+        format!("second {}, safety factor {}", self.seconds, self.map.safety_factor())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    x: i64,
+    y: i64,
+    v_x: i64,
+    v_y: i64
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> Result<Self, Self::Err> {
+        // e.g.: p=23,10 v=-27,-20
+        let robo_regex = Regex::new(r"^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)$").unwrap();
+        let capture = robo_regex.captures(l).ok_or_else(|| ParseError::new(1, "'p=<x>,<y> v=<x>,<y>'", l))?;
+
+        let numbers = capture.iter().skip(1).flatten()
+            .map(|m| parse::number::<i64>(m.as_str(), m.start() + 1))
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+
+        Ok(Robot { x: numbers[0], y: numbers[1], v_x: numbers[2], v_y: numbers[3] })
+    }
+}
+
+impl Robot {
+    pub fn move_in_grid(&mut self, steps: i64, max_x: i64, max_y: i64) {
+        self.x += steps * self.v_x;
+        self.y += steps * self.v_y;
+
+        // check boundaries
+        if self.x >= max_x {
+            self.x %= max_x;
+        } else if self.x < 0 {
+            let wrap = self.x % max_x;
+            if wrap == 0 {
+                self.x = 0;
+            } else {
+                self.x = max_x + wrap; // wrap is negative
+            }
+        }
+
+        if self.y >= max_y {
+            self.y %= max_y;
+        } else if self.y < 0 {
+            let wrap = self.y % max_y;
+            if wrap == 0 {
+                self.y = 0;
+            } else {
+                self.y = max_y + wrap;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse_with_grid(&Input::from_file("test-input").unwrap(), (11, 7)).unwrap());
+
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn test_part2() {
+        // both robots are on (1, 0) after one second
+        let map = parse_with_grid(&Input::parse("p=0,0 v=1,0\np=1,0 v=0,0"), (11, 7)).unwrap();
+        assert_eq!(part2(&map), 2);
+
+        let map = parse_with_grid(&Input::parse("p=0,0 v=1,0\np=0,0 v=1,0"), (11, 7)).unwrap();
+        assert!(matches!(part2(&map), Answer::NoSolution(_)));
+    }
+
+    #[test]
+    fn test_motion() {
+        let map = parse_with_grid(&Input::parse("p=2,4 v=2,-3"), (11, 7)).unwrap();
+        let mut motion = Motion::new(&map);
+        (0..5).for_each(|_| { motion.step(); });
+
+        assert_eq!(motion.frame().find_char('#'), Some((1, 3)));
+        assert!(motion.status().starts_with("second 5"));
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::HashSet;
+use aoc_common::{Answer, Coord, Grid, Input, ParseError, Simulation};
+
+pub fn parse(input: &Input) -> Result<Warehouse, ParseError> {
+    let mut inputs = input.numbered_sections().into_iter();
+    let (map_line, map_lines) = inputs.next()
+        .ok_or_else(|| ParseError::new(0, "a map", "").at_line(1))?;
+    let (movements_line, movement_lines) = inputs.next()
+        .ok_or_else(|| ParseError::new(0, "movements", "").at_line(input.lines().len() + 1))?;
+
+    let objects = Grid::parse(map_lines, |c| c)
+        .map_err(ParseError::from)
+        .and_then(|grid| grid.try_map(|c| Object::try_from(*c)))
+        .map_err(|e| e.offset(map_line))?;
+    if objects.find(|o| *o == Object::Robot).is_none() {
+        return Err(ParseError::new(0, "a robot ('@')", "").at_line(map_line));
+    }
+
+    let mut movements: Vec<Movement> = vec![];
+    for (index, line) in movement_lines.iter().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            let movement = Movement::try_from(c)
+                .map_err(|e| e.at_cell((column as i64, index as i64)).offset(movements_line))?;
+            movements.push(movement);
+        }
+    }
+
+    Ok(Warehouse { objects, movements })
+}
+
+pub fn part1(warehouse: &Warehouse) -> Answer {
+    let mut map = Map::from(&warehouse.objects);
+
+    warehouse.movements.iter().for_each(|m| {
+        map.move_robot(m.clone());
+    });
+
+    Answer::from(sum_of_coordinates(&map))
+}
+
+pub fn part2(warehouse: &Warehouse) -> Answer {
+    let mut map = Map::wide(&warehouse.objects);
+
+    warehouse.movements.iter().for_each(|m| {
+        map.move_robot(m.clone());
+    });
+
+    Answer::from(sum_of_coordinates(&map))
+}
+
+// a wide box is measured from its left edge
+fn sum_of_coordinates(map: &Map) -> usize {
+    map.objects.find_all(|o| *o == Object::Box || *o == Object::BoxLeft).iter()
+        .map(|c| {
+            (100 * c.1 + c.0) as usize
+        })
+        .sum()
+}
+
+pub struct Warehouse {
+    objects: Grid<Object>,
+    movements: Vec<Movement>
+}
+
+#[derive(Debug)]
+struct Map {
+    robot_position: Coord,
+    objects: Grid<Object>
+}
+
+impl Map {
+    pub fn from(objects: &Grid<Object>) -> Self {
+        let robot_position = objects.find(|o| *o == Object::Robot).expect("Map has no robot");
+
+        Map { robot_position, objects: objects.clone() }
+    }
+
+    // everything except the robot is twice as wide
+    pub fn wide(objects: &Grid<Object>) -> Self {
+        let mut wide_objects = Grid::new(objects.width() * 2, objects.height(), Object::Nothing);
+        objects.iter().for_each(|(c, o)| {
+            let (left, right) = match o {
+                Object::Wall => (Object::Wall, Object::Wall),
+                Object::Box => (Object::BoxLeft, Object::BoxRight),
+                Object::Nothing => (Object::Nothing, Object::Nothing),
+                Object::Robot => (Object::Robot, Object::Nothing),
+                Object::BoxLeft | Object::BoxRight => panic!("Map at {:?} is already wide", c)
+            };
+            wide_objects[(c.0 * 2, c.1)] = left;
+            wide_objects[(c.0 * 2 + 1, c.1)] = right;
+        });
+
+        Map::from(&wide_objects)
+    }
+
+    pub fn move_robot(&mut self, m: Movement) {
+        let next_coord = m.next(self.robot_position);
+        let robot_neighbour = self.objects[next_coord];
+
+        let can_move = match robot_neighbour {
+            Object::Wall => false, // if next neighbour is wall, don't move at all
+            Object::Box | Object::BoxLeft | Object::BoxRight => self.move_boxes(next_coord, &m),
+            Object::Nothing => true,
+            Object::Robot => panic!("There is a second robot at {:?}", next_coord)
+        };
+
+        if can_move {
+            self.objects[self.robot_position] = Object::Nothing;
+            self.objects[next_coord] = Object::Robot;
+            self.robot_position = next_coord;
+        }
+    }
+
+    // moves all boxes which are pushed by a box (half) at start. Either all of them move or none.
+    pub fn move_boxes(&mut self, start: Coord, movement: &Movement) -> bool {
+        let box_parts = match self.find_pushed_box_parts(start, movement) {
+            Some(box_parts) => box_parts,
+            None => return false
+        };
+
+        // move the part which is furthest away in movement direction first,
+        // so that no part is overwritten before it was moved
+        let mut box_parts: Vec<Coord> = box_parts.into_iter().collect();
+        box_parts.sort_by_key(|c| {
+            let next = movement.next((0, 0));
+            -(c.0 * next.0 + c.1 * next.1)
+        });
+        box_parts.iter().for_each(|c| {
+            let next = movement.next(*c);
+            self.objects[next] = self.objects[*c];
+            self.objects[*c] = Object::Nothing;
+        });
+
+        true
+    }
+
+    // all box parts which would be pushed, None if any of them is blocked by a wall
+    fn find_pushed_box_parts(&self, start: Coord, movement: &Movement) -> Option<HashSet<Coord>> {
+        let mut box_parts: HashSet<Coord> = HashSet::new();
+        let mut to_check: Vec<Coord> = vec![start];
+
+        while let Some(c) = to_check.pop() {
+            if !box_parts.insert(c) {
+                continue;
+            }
+
+            // pushing one half vertically also pushes the other half.
+            // horizontally, the other half is next in line anyway
+            if movement.is_vertical() {
+                match self.objects[c] {
+                    Object::BoxLeft => to_check.push((c.0 + 1, c.1)),
+                    Object::BoxRight => to_check.push((c.0 - 1, c.1)),
+                    _ => {}
+                }
+            }
+
+            let next = movement.next(c);
+            match self.objects[next] {
+                Object::Wall => return None,
+                Object::Box | Object::BoxLeft | Object::BoxRight => to_check.push(next),
+                _ => {}
+            }
+        }
+
+        Some(box_parts)
+    }
+
+    pub fn render(&self) -> Grid<char> {
+        self.objects.map(|o| match o {
+            Object::Nothing => '.',
+            Object::Wall => '#',
+            Object::Box => 'O',
+            Object::BoxLeft => '[',
+            Object::BoxRight => ']',
+            Object::Robot => '@'
+        })
+    }
+}
+
+// the robot move by move, on the wide map for part 2. For `aoc visualize`
+pub struct Moves {
+    map: Map,
+    movements: Vec<Movement>,
+    done: usize
+}
+
+impl Moves {
+    pub fn new(warehouse: &Warehouse, wide: bool) -> Self {
+        let map = if wide {
+            Map::wide(&warehouse.objects)
+        } else {
+            Map::from(&warehouse.objects)
+        };
+
+        Moves { map, movements: warehouse.movements.clone(), done: 0 }
+    }
+}
+
+impl Simulation for Moves {
+    fn step(&mut self) -> bool {
+        match self.movements.get(self.done) {
+            Some(m) => {
+                self.map.move_robot(m.clone());
+                self.done += 1;
+                true
+            },
+            None => false
+        }
+    }
+
+    fn frame(&self) -> Grid<char> {
+        self.map.render()
+    }
+
+    fn status(&self) -> String {
+        let next = match self.movements.get(self.done) {
+            Some(m) => format!(", next {}", char::from(m)),
+            None => String::new()
+        };
+
+        format!("move {} of {}{}, sum of coordinates {}", self.done, self.movements.len(), next, sum_of_coordinates(&self.map))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Movement {
+    Up, Down, Left, Right
+}
+
+impl TryFrom<char> for Movement {
+    type Error = ParseError;
+
+    fn try_from(s: char) -> Result<Self, Self::Error> {
+        match s {
+            '^' => Ok(Movement::Up),
+            'v' => Ok(Movement::Down),
+            '<' => Ok(Movement::Left),
+            '>' => Ok(Movement::Right),
+            _ => Err(ParseError::new(0, "a movement ('^', 'v', '<' or '>')", &s.to_string()))
+        }
+    }
+}
+
+impl From<&Movement> for char {
+    fn from(m: &Movement) -> Self {
+        match m {
+            Movement::Up => '^',
+            Movement::Down => 'v',
+            Movement::Left => '<',
+            Movement::Right => '>'
+        }
+    }
+}
+
+impl Movement {
+    pub fn next(&self, c: Coord) -> Coord {
+        match self {
+            Movement::Up => (c.0, c.1 - 1),
+            Movement::Down => (c.0, c.1 + 1),
+            Movement::Left => (c.0 - 1, c.1),
+            Movement::Right => (c.0 + 1, c.1)
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Movement::Up | Movement::Down)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Object {
+    Robot, Wall, Box, BoxLeft, BoxRight, Nothing
+}
+
+// the input only contains narrow boxes
+impl TryFrom<char> for Object {
+    type Error = ParseError;
+
+    fn try_from(s: char) -> Result<Self, Self::Error> {
+        match s {
+            '#' => Ok(Object::Wall),
+            '@' => Ok(Object::Robot),
+            'O' => Ok(Object::Box),
+            '.' => Ok(Object::Nothing),
+            _ => Err(ParseError::new(0, "a map symbol ('#', '@', 'O' or '.')", &s.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_file1() {
+        let result = part1(&parse(&Input::from_file("test-input-1").unwrap()).unwrap());
+
+        assert_eq!(result, 10092);
+    }
+
+    #[test]
+    fn test_part1_file2() {
+        let result = part1(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+
+        assert_eq!(result, 2028);
+    }
+
+    #[test]
+    fn test_part2_file1() {
+        let result = part2(&parse(&Input::from_file("test-input-1").unwrap()).unwrap());
+
+        assert_eq!(result, 9021);
+    }
+
+    #[test]
+    fn test_part2_file2() {
+        let result = part2(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+
+        assert_eq!(result, 1751);
+    }
+
+    #[test]
+    fn test_blocked_push() {
+        let input = Input::parse("#######\n#...#.#\n#.....#\n#..OO@#\n#..O..#\n#.....#\n#######\n\n<vv<<^^<<^^");
+
+        assert_eq!(part2(&parse(&input).unwrap()), 618);
+    }
+
+    #[test]
+    fn test_moves() {
+        let warehouse = parse(&Input::from_file("test-input-2").unwrap()).unwrap();
+        let mut moves = Moves::new(&warehouse, false);
+        while moves.step() {}
+
+        assert_eq!(sum_of_coordinates(&moves.map), 2028);
+        assert_eq!(moves.frame().find_char('@'), Some((4, 4)));
+        assert!(moves.status().starts_with("move 15 of 15"));
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::search::{dijkstra, SearchResult};
+use aoc_common::{Answer, Grid, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Map, ParseError> {
+    Map::try_from(&input.grid()?)
+}
+
+pub fn part1(map: &Map) -> Answer {
+    match map.solve().cost() {
+        Some(cost) => Answer::from(cost),
+        None => Answer::NoSolution(String::from("there is no path from S to E"))
+    }
+}
+
+pub fn part2(map: &Map) -> Answer {
+    let result = map.solve();
+    if result.goals().is_empty() {
+        return Answer::NoSolution(String::from("there is no path from S to E"));
+    }
+
+    // all states on any best path, but a tile can be part of several states (directions)
+    let tiles: HashSet<(i64, i64)> = result.states_on_paths(result.goals()).iter()
+        .map(|(point, _)| *point)
+        .collect();
+
+    Answer::from(tiles.len())
+}
+
+#[derive(Debug)]
+pub struct Map {
+    edges: HashMap<(i64, i64), Vec<(i64, i64)>>,
+    start: (i64, i64),
+    end: (i64, i64)
+}
+
+impl TryFrom<&Grid<char>> for Map {
+    type Error = ParseError;
+
+    fn try_from(grid: &Grid<char>) -> Result<Self, Self::Error> {
+        let start = grid.find_char('S')
+            .ok_or_else(|| ParseError::new(0, "a start ('S')", "").at_line(1))?;
+        let end = grid.find_char('E')
+            .ok_or_else(|| ParseError::new(0, "an end ('E')", "").at_line(1))?;
+
+        let mut edges: HashMap<(i64, i64), Vec<(i64, i64)>> = HashMap::new();
+        for p in grid.find_all(|c| *c != '#') {
+            let real_neighbours: Vec<(i64, i64)> = grid.neighbours4(p)
+                .filter(|n| grid[*n] != '#')
+                .collect();
+            edges.insert(p, real_neighbours);
+        }
+
+        Ok(Map { edges, start, end })
+    }
+}
+
+impl Map {
+    pub fn solve(&self) -> SearchResult<((i64, i64), Direction)> {
+        let result = dijkstra(
+            (self.start, Direction::East),
+            |(point, direction)| {
+                self.edges.get(point).unwrap().iter().map(|neighbour| {
+                    let (from_to_distance, from_to_direction) = Map::distance(point, neighbour, direction.clone());
+                    ((*neighbour, from_to_direction), from_to_distance)
+                }).collect::<Vec<_>>()
+            },
+            |(point, _)| *point == self.end
+        );
+
+        result
+    }
+
+    fn new_direction(from: &(i64, i64), to: &(i64, i64)) -> Direction {
+        let x_change = to.0 - from.0;
+        let y_change = to.1 - from.1;
+
+        if x_change < 0 {
+            Direction::West
+        } else if x_change > 0 {
+            Direction::East
+        } else if y_change < 0 {
+            Direction::North
+        } else {
+            Direction::South
+        }
+    }
+
+    fn distance(from: &(i64, i64), to: &(i64, i64), from_direction: Direction) -> (i64, Direction) {
+        let from_to_direction = Map::new_direction(from, to);
+
+        let costs = match from_direction {
+            Direction::North => {
+                match from_to_direction {
+                    Direction::North => 1,
+                    Direction::West => 1001,
+                    Direction::East => 1001,
+                    Direction::South => 2001
+                }
+            },
+            Direction::South => {
+                match from_to_direction {
+                    Direction::North => 2001,
+                    Direction::West => 1001,
+                    Direction::East => 1001,
+                    Direction::South => 1
+                }
+            },
+            Direction::East => {
+                match from_to_direction {
+                    Direction::North => 1001,
+                    Direction::West => 2001,
+                    Direction::East => 1,
+                    Direction::South => 1001
+                }
+            },
+            Direction::West => {
+                match from_to_direction {
+                    Direction::North => 1001,
+                    Direction::West => 1,
+                    Direction::East => 2001,
+                    Direction::South => 1001
+                }
+            },
+        };
+
+        (costs, from_to_direction)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Direction {
+    North, South, East, West
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Direction::North => "^",
+            Direction::South => "v",
+            Direction::East => ">",
+            Direction::West => "<"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_file1() {
+        let result = part1(&parse(&Input::from_file("test-input-1").unwrap()).unwrap());
+
+        assert_eq!(result, 7036);
+    }
+
+    #[test]
+    fn test_part1_file2() {
+        let result = part1(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+
+        assert_eq!(result, 11048);
+    }
+
+    #[test]
+    fn test_part2_file1() {
+        let result = part2(&parse(&Input::from_file("test-input-1").unwrap()).unwrap());
+
+        assert_eq!(result, 45);
+    }
+
+    #[test]
+    fn test_part2_file2() {
+        let result = part2(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let map = parse(&Input::parse("#####\n#S#E#\n#####")).unwrap();
+
+        assert_eq!(part1(&map), Answer::NoSolution(String::from("there is no path from S to E")));
+        assert_eq!(part2(&map), Answer::NoSolution(String::from("there is no path from S to E")));
+    }
+}
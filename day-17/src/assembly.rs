@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use aoc_common::parse::{self, Token};
+use aoc_common::ParseError;
+
+// the eight instructions, in the order of their opcodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv, Bxl, Bst, Jnz, Bxc, Out, Bdv, Cdv
+}
+
+const OPCODES: [Opcode; 8] = [
+    Opcode::Adv, Opcode::Bxl, Opcode::Bst, Opcode::Jnz, Opcode::Bxc, Opcode::Out, Opcode::Bdv, Opcode::Cdv
+];
+
+impl Opcode {
+    pub fn from_value(value: i64) -> Option<Opcode> {
+        usize::try_from(value).ok().and_then(|v| OPCODES.get(v)).copied()
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Opcode> {
+        OPCODES.iter().find(|o| o.mnemonic() == mnemonic).copied()
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv"
+        }
+    }
+
+    // a combo operand is 0-3 or one of the registers, any other operand is a literal
+    pub fn has_combo_operand(&self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+// problems which would stop (or crash) the cpu, found without running the program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    // combo operand 7 is reserved and doesn't appear in valid programs
+    ReservedOperand { address: usize },
+    // the program ends with an opcode
+    MissingOperand { address: usize }
+}
+
+impl Diagnostic {
+    pub fn address(&self) -> usize {
+        match self {
+            Diagnostic::ReservedOperand { address } | Diagnostic::MissingOperand { address } => *address
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::ReservedOperand { address } => write!(f, "address {}: combo operand 7 is reserved", address),
+            Diagnostic::MissingOperand { address } => write!(f, "address {}: the opcode has no operand", address)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: Opcode,
+    // None if the program ends before the operand
+    pub operand: Option<i64>
+}
+
+// e.g. `adv A>>3`, `bxl 5`, `out B%8`
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operand = match self.operand {
+            Some(operand) => operand,
+            None => return write!(f, "{}", self.opcode.mnemonic())
+        };
+
+        match self.opcode {
+            Opcode::Adv | Opcode::Bdv | Opcode::Cdv => write!(f, "{} A>>{}", self.opcode.mnemonic(), combo(operand)),
+            Opcode::Bst | Opcode::Out if (0..4).contains(&operand) => write!(f, "{} {}", self.opcode.mnemonic(), operand),
+            Opcode::Bst | Opcode::Out => write!(f, "{} {}%8", self.opcode.mnemonic(), combo(operand)),
+            Opcode::Bxl | Opcode::Jnz => write!(f, "{} {}", self.opcode.mnemonic(), operand),
+            // the operand is ignored, it's only written to keep the program as it is
+            Opcode::Bxc if operand == 0 => write!(f, "{}", self.opcode.mnemonic()),
+            Opcode::Bxc => write!(f, "{} {}", self.opcode.mnemonic(), operand)
+        }
+    }
+}
+
+fn combo(operand: i64) -> String {
+    match operand {
+        4 => String::from("A"),
+        5 => String::from("B"),
+        6 => String::from("C"),
+        7 => String::from("?7"),
+        literal => literal.to_string()
+    }
+}
+
+pub struct Disassembly {
+    pub instructions: Vec<Instruction>,
+    pub diagnostics: Vec<Diagnostic>
+}
+
+// one instruction per line with its address. Diagnostics follow their instruction as comments.
+impl Display for Disassembly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for instruction in &self.instructions {
+            let line = format!("{:>3}: {}", instruction.address, instruction);
+            let comments: Vec<String> = self.diagnostics.iter()
+                .filter(|d| d.address() == instruction.address)
+                .map(|d| d.to_string())
+                .collect();
+
+            if comments.is_empty() {
+                writeln!(f, "{}", line)?;
+            } else {
+                writeln!(f, "{:<16}; {}", line, comments.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// reads the program without running it. The values are 3 bit numbers, as checked by the parser.
+pub fn disassemble(program: &[i64]) -> Disassembly {
+    let mut instructions: Vec<Instruction> = vec![];
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+
+    for (index, pair) in program.chunks(2).enumerate() {
+        let address = index * 2;
+        let opcode = Opcode::from_value(pair[0]).expect("Program values are 3 bit numbers");
+        let operand = pair.get(1).copied();
+
+        match operand {
+            None => diagnostics.push(Diagnostic::MissingOperand { address }),
+            Some(7) if opcode.has_combo_operand() => diagnostics.push(Diagnostic::ReservedOperand { address }),
+            _ => {}
+        }
+        instructions.push(Instruction { address, opcode, operand });
+    }
+
+    Disassembly { instructions, diagnostics }
+}
+
+// the initial registers and the program, printed in the format of the puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assembly {
+    pub registers: [i64; 3],
+    pub program: Vec<i64>
+}
+
+impl Display for Assembly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let program: Vec<String> = self.program.iter().map(|v| v.to_string()).collect();
+
+        writeln!(f, "Register A: {}", self.registers[0])?;
+        writeln!(f, "Register B: {}", self.registers[1])?;
+        writeln!(f, "Register C: {}", self.registers[2])?;
+        writeln!(f)?;
+        writeln!(f, "Program: {}", program.join(","))
+    }
+}
+
+// One instruction per line, in the syntax of the disassembly. Lines may start with
+// labels (`loop:`), which are targets for `jnz`, or with the address of the instruction
+// (`4:`), which has to match. `;` starts a comment. Registers are 0 unless they are
+// set with a directive, e.g.:
+//
+//   .register A 729
+//   loop: adv A>>1
+//         out A%8
+//         jnz loop
+pub fn assemble(source: &str) -> Result<Assembly, ParseError> {
+    let mut registers = [0; 3];
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    // the operands of jnz are resolved once all labels are known
+    let mut instructions: Vec<(usize, Opcode, Option<Token>)> = vec![];
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let code = line.split(';').next().unwrap_or_default();
+        let mut words = words(code).into_iter().peekable();
+
+        if let Some((".register", _)) = words.peek() {
+            words.next();
+            let (name, column) = words.next().ok_or_else(|| ParseError::new(code.len() + 1, "a register (A, B or C)", "").at_line(line_number))?;
+            let register = ["A", "B", "C"].iter().position(|r| *r == name)
+                .ok_or_else(|| ParseError::new(column, "a register (A, B or C)", name).at_line(line_number))?;
+            let (value, column) = words.next().ok_or_else(|| ParseError::new(code.len() + 1, "a value", "").at_line(line_number))?;
+            // like the puzzle input, the value is a non-negative i64
+            let value = parse::number::<u64>(value, column)
+                .and_then(|v| i64::try_from(v).map_err(|_| ParseError::new(column, &format!("a number up to {}", i64::MAX), value)))
+                .map_err(|e| e.at_line(line_number))?;
+            registers[register] = value;
+            expect_end(words.next(), line_number)?;
+            continue;
+        }
+
+        let address = instructions.len() * 2;
+        while let Some((word, column)) = words.next_if(|(w, _)| w.ends_with(':')) {
+            let name = word.trim_end_matches(':');
+            match name.parse::<usize>() {
+                Ok(a) if a == address => {},
+                Ok(_) => return Err(ParseError::new(column, &format!("address {}", address), name).at_line(line_number)),
+                Err(_) if !is_label(name) => return Err(ParseError::new(column, "a label", name).at_line(line_number)),
+                Err(_) => {
+                    if labels.insert(name, address).is_some() {
+                        return Err(ParseError::new(column, "a new label", name).at_line(line_number));
+                    }
+                }
+            }
+        }
+
+        let (mnemonic, column) = match words.next() {
+            Some(word) => word,
+            None => continue
+        };
+        let opcode = Opcode::from_mnemonic(mnemonic)
+            .ok_or_else(|| ParseError::new(column, "an instruction (adv, bxl, bst, jnz, bxc, out, bdv or cdv)", mnemonic).at_line(line_number))?;
+        let operand = words.next();
+        expect_end(words.next(), line_number)?;
+        if operand.is_none() && opcode != Opcode::Bxc {
+            return Err(ParseError::new(code.trim_end().len() + 1, "an operand", "").at_line(line_number));
+        }
+
+        instructions.push((line_number, opcode, operand));
+    }
+
+    let mut program: Vec<i64> = vec![];
+    for (line_number, opcode, operand) in instructions {
+        let value = match operand {
+            None => Ok(0),
+            Some(operand) if opcode.has_combo_operand() => combo_operand(operand, opcode),
+            Some((label, column)) if opcode == Opcode::Jnz && is_label(label) => match labels.get(label) {
+                Some(address) if *address < 8 => Ok(*address as i64),
+                Some(address) => Err(ParseError::new(column, "a label at address 0-7", &format!("{} at address {}", label, address))),
+                None => Err(ParseError::new(column, "a defined label", label))
+            },
+            Some(operand) => literal_operand(operand)
+        }.map_err(|e| e.at_line(line_number))?;
+
+        program.push(opcode as i64);
+        program.push(value);
+    }
+
+    Ok(Assembly { registers, program })
+}
+
+// the words of a line, with the column they start at
+fn words(line: &str) -> Vec<Token<'_>> {
+    let mut words: Vec<Token> = vec![];
+    let mut start: Option<usize> = None;
+
+    for (index, c) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(index),
+            (Some(s), true) => {
+                words.push((&line[s..index], s + 1));
+                start = None;
+            },
+            _ => {}
+        }
+    }
+
+    words
+}
+
+fn expect_end(word: Option<Token>, line_number: usize) -> Result<(), ParseError> {
+    match word {
+        Some((word, column)) => Err(ParseError::new(column, "the end of the line", word).at_line(line_number)),
+        None => Ok(())
+    }
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// `A>>B` of the divisions and `B%8` of bst and out are optional, `B` is enough
+fn combo_operand((text, column): Token, opcode: Opcode) -> Result<i64, ParseError> {
+    let operand = match opcode {
+        Opcode::Adv | Opcode::Bdv | Opcode::Cdv => text.strip_prefix("A>>").unwrap_or(text),
+        _ => text.strip_suffix("%8").unwrap_or(text)
+    };
+
+    match operand {
+        "0" | "1" | "2" | "3" => Ok(operand.parse::<i64>().unwrap()),
+        "A" => Ok(4),
+        "B" => Ok(5),
+        "C" => Ok(6),
+        "?7" => Ok(7),
+        _ => Err(ParseError::new(column, "a combo operand (0-3, A, B or C)", text))
+    }
+}
+
+fn literal_operand((text, column): Token) -> Result<i64, ParseError> {
+    match parse::number::<i64>(text, column) {
+        Ok(value) if (0..8).contains(&value) => Ok(value),
+        _ => Err(ParseError::new(column, "a 3 bit number (0-7)", text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble() {
+        let disassembly = disassemble(&[0, 1, 5, 4, 3, 0]);
+
+        assert_eq!(disassembly.to_string(), "  0: adv A>>1\n  2: out A%8\n  4: jnz 0\n");
+        assert!(disassembly.diagnostics.is_empty());
+
+        let lines: Vec<String> = disassemble(&[2, 4, 1, 5, 7, 5, 4, 3, 6, 2]).instructions.iter().map(|i| i.to_string()).collect();
+        assert_eq!(lines, vec!["bst A%8", "bxl 5", "cdv A>>B", "bxc 3", "bdv A>>2"]);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let disassembly = disassemble(&[5, 7, 1, 7, 0]);
+
+        assert_eq!(disassembly.diagnostics, vec![
+            Diagnostic::ReservedOperand { address: 0 },
+            Diagnostic::MissingOperand { address: 4 }
+        ]);
+        assert_eq!(disassembly.to_string(), "  0: out ?7%8   ; address 0: combo operand 7 is reserved\n  2: bxl 7\n  4: adv        ; address 4: the opcode has no operand\n");
+    }
+
+    #[test]
+    fn test_assemble() {
+        let source = ".register A 729\nloop: adv A>>1  ; A = A / 2\n      out A%8\n      jnz loop\n";
+        let assembly = assemble(source).unwrap();
+
+        assert_eq!(assembly.to_string(), "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n");
+        let cpu = crate::parse(&aoc_common::Input::parse(&assembly.to_string())).unwrap();
+        assert_eq!(crate::part1(&cpu), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let programs = [
+            vec![0, 3, 5, 4, 3, 0],
+            vec![2, 4, 1, 5, 7, 5, 4, 3, 1, 6, 0, 3, 5, 5, 3, 0],
+            vec![4, 6, 5, 7, 3, 5, 6, 0]
+        ];
+
+        for program in programs {
+            let source = disassemble(&program).to_string();
+            assert_eq!(assemble(&source).unwrap().program, program, "{}", source);
+        }
+
+        // the bare opcode without its operand doesn't assemble
+        let error = assemble(&disassemble(&[0, 3, 5]).to_string()).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "an operand"));
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let error = assemble("adv A\njnz end").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 5, "end"));
+
+        let error = assemble("start: bst D%8").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+
+        let error = assemble("0: bxl 1\n4: bxl 2").unwrap_err();
+        assert_eq!(error.expected, "address 2");
+
+        assert!(assemble("out").is_err());
+        assert!(assemble("bxl 8").is_err());
+
+        let error = assemble(".register B 9223372036854775808").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 13, "9223372036854775808"));
+        assert_eq!(assemble(".register B 9223372036854775807").unwrap().registers[1], i64::MAX);
+    }
+}
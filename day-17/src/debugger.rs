@@ -0,0 +1,341 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::str::FromStr;
+use crate::assembly::{Instruction, Opcode};
+use crate::Cpu;
+
+pub const HELP: &str = "step [n]        (s) execute n instructions, default 1
+continue        (c) run until a breakpoint, a watched register changes or the cpu halts
+break <address> (b) stop when the instruction pointer reaches the address
+delete <address>    remove a breakpoint
+watch <A|B|C>   (w) stop when the register changes
+unwatch <A|B|C>     stop watching the register
+set <A|B|C> <n>     change a register, e.g. to try a candidate for A
+print           (p) the registers and the next instruction
+output          (o) the output so far
+list            (l) the program, > marks the next instruction and * the breakpoints
+rewind [n]      (r) undo the last n steps (or changes of a register), default 1
+reset               start over with the initial registers
+quit            (q)";
+
+// `continue` gives up after this many instructions, the program may loop forever
+const CONTINUE_LIMIT: usize = 1_000_000;
+// the oldest states are dropped, so a long run doesn't take all memory
+const HISTORY_LIMIT: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Register {
+    A, B, C
+}
+
+impl FromStr for Register {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "a" => Ok(Register::A),
+            "B" | "b" => Ok(Register::B),
+            "C" | "c" => Ok(Register::C),
+            _ => Err(format!("'{}' is not a register (A, B or C)", s))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(usize),
+    Delete(usize),
+    Watch(Register),
+    Unwatch(Register),
+    Set(Register, i64),
+    Print,
+    Output,
+    List,
+    Rewind(usize),
+    Reset,
+    Help,
+    Quit
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let number = |word: Option<&&str>, default: Option<usize>| match word {
+            Some(word) => word.parse::<usize>().map_err(|_| format!("'{}' is not a number", word)),
+            None => default.ok_or_else(|| String::from("a number is missing"))
+        };
+        let register = |word: Option<&&str>| match word {
+            Some(word) => word.parse::<Register>(),
+            None => Err(String::from("a register is missing"))
+        };
+
+        let command = match words.first().copied() {
+            Some("step" | "s") => Command::Step(number(words.get(1), Some(1))?),
+            Some("continue" | "c") => Command::Continue,
+            Some("break" | "b") => Command::Break(number(words.get(1), None)?),
+            Some("delete") => Command::Delete(number(words.get(1), None)?),
+            Some("watch" | "w") => Command::Watch(register(words.get(1))?),
+            Some("unwatch") => Command::Unwatch(register(words.get(1))?),
+            Some("set") => {
+                let value = words.get(2).ok_or("a value is missing")?;
+                let value = value.parse::<i64>().map_err(|_| format!("'{}' is not a number", value))?;
+                Command::Set(register(words.get(1))?, value)
+            },
+            Some("print" | "p") => Command::Print,
+            Some("output" | "o") => Command::Output,
+            Some("list" | "l") => Command::List,
+            Some("rewind" | "r") => Command::Rewind(number(words.get(1), Some(1))?),
+            Some("reset") => Command::Reset,
+            Some("help" | "h") => Command::Help,
+            Some("quit" | "q") => Command::Quit,
+            Some(other) => return Err(format!("unknown command '{}', try 'help'", other)),
+            None => return Err(String::from("try 'help'"))
+        };
+
+        Ok(command)
+    }
+}
+
+// steps through the program of a cpu. Every state before a step is kept, so steps can be undone.
+pub struct Debugger {
+    initial: Cpu,
+    cpu: Cpu,
+    steps: usize,
+    // (steps, cpu) before each step or change of a register
+    history: VecDeque<(usize, Cpu)>,
+    breakpoints: BTreeSet<usize>,
+    watches: BTreeSet<Register>
+}
+
+impl Debugger {
+    pub fn new(cpu: &Cpu) -> Self {
+        Debugger {
+            initial: cpu.clone(),
+            cpu: cpu.clone(),
+            steps: 0,
+            history: VecDeque::new(),
+            breakpoints: BTreeSet::new(),
+            watches: BTreeSet::new()
+        }
+    }
+
+    // what to print for the command, Quit is up to the caller
+    pub fn execute(&mut self, command: &Command) -> String {
+        match command {
+            Command::Step(n) => self.run(*n),
+            Command::Continue => self.run(CONTINUE_LIMIT),
+            Command::Break(address) => {
+                self.breakpoints.insert(*address);
+                format!("breakpoint at {}", address)
+            },
+            Command::Delete(address) => match self.breakpoints.remove(address) {
+                true => format!("removed the breakpoint at {}", address),
+                false => format!("there is no breakpoint at {}", address)
+            },
+            Command::Watch(register) => {
+                self.watches.insert(*register);
+                format!("watching {:?} = {}", register, self.register(*register))
+            },
+            Command::Unwatch(register) => {
+                self.watches.remove(register);
+                format!("not watching {:?}", register)
+            },
+            Command::Set(register, value) => {
+                self.remember();
+                match register {
+                    Register::A => self.cpu.register_a = *value,
+                    Register::B => self.cpu.register_b = *value,
+                    Register::C => self.cpu.register_c = *value
+                }
+                self.location()
+            },
+            Command::Print => self.location(),
+            Command::Output => format!("output: {}", self.cpu.read_output()),
+            Command::List => self.list(),
+            Command::Rewind(n) => self.rewind(*n),
+            Command::Reset => {
+                *self = Debugger { breakpoints: self.breakpoints.clone(), watches: self.watches.clone(), ..Debugger::new(&self.initial) };
+                self.location()
+            },
+            Command::Help => String::from(HELP),
+            Command::Quit => String::new()
+        }
+    }
+
+    // executes up to `limit` instructions, stops early at breakpoints, changes of
+    // watched registers and when the cpu halts
+    fn run(&mut self, limit: usize) -> String {
+        let mut reasons: Vec<String> = vec![];
+
+        for _ in 0..limit {
+            let before: Vec<(Register, i64)> = self.watches.iter().map(|r| (*r, self.register(*r))).collect();
+            if let Err(reason) = self.step() {
+                reasons.push(reason);
+                break;
+            }
+
+            before.iter()
+                .filter(|(r, value)| self.register(*r) != *value)
+                .for_each(|(r, value)| reasons.push(format!("{:?} changed from {} to {}", r, value, self.register(*r))));
+            if self.breakpoints.contains(&(self.cpu.instruction_pointer as usize)) {
+                reasons.push(format!("breakpoint at {}", self.cpu.instruction_pointer));
+            }
+            if !reasons.is_empty() {
+                break;
+            }
+        }
+        if reasons.is_empty() && limit == CONTINUE_LIMIT {
+            reasons.push(format!("still running after {} instructions", limit));
+        }
+
+        reasons.push(self.location());
+        reasons.join("\n")
+    }
+
+    // Err with the reason if the cpu can't execute the next instruction
+    fn step(&mut self) -> Result<(), String> {
+        if self.cpu.halted() {
+            return Err(String::from("the cpu halted"));
+        }
+
+        // a failed instruction doesn't change the cpu, so there's nothing to remember
+        self.remember();
+        if let Err(e) = self.cpu.tick() {
+            self.history.pop_back();
+            return Err(e.to_string());
+        }
+        self.steps += 1;
+
+        Ok(())
+    }
+
+    fn remember(&mut self) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back((self.steps, self.cpu.clone()));
+    }
+
+    fn rewind(&mut self, n: usize) -> String {
+        if n > self.history.len() {
+            return format!("can rewind at most {} step(s)", self.history.len());
+        }
+        if n == 0 {
+            return self.location();
+        }
+
+        let (steps, cpu) = self.history.drain(self.history.len() - n..).next().unwrap();
+        self.steps = steps;
+        self.cpu = cpu;
+
+        self.location()
+    }
+
+    // the instruction at the instruction pointer, None once the cpu halted.
+    // Jumps may go to odd addresses, so this doesn't use the disassembly of the whole program.
+    fn instruction(&self) -> Option<Instruction> {
+        let address = self.cpu.instruction_pointer as usize;
+        let opcode = Opcode::from_value(*self.cpu.instructions.get(address)?)?;
+
+        Some(Instruction { address, opcode, operand: self.cpu.instructions.get(address + 1).copied() })
+    }
+
+    fn register(&self, register: Register) -> i64 {
+        match register {
+            Register::A => self.cpu.register_a,
+            Register::B => self.cpu.register_b,
+            Register::C => self.cpu.register_c
+        }
+    }
+
+    // e.g. `step 3 | A=364 B=0 C=0 | next 2: out A%8 | output 4`
+    fn location(&self) -> String {
+        let next = match self.instruction() {
+            Some(instruction) => format!("next {}: {}", instruction.address, instruction),
+            None => String::from("halted")
+        };
+        let output = match self.cpu.read_output() {
+            output if output.is_empty() => output,
+            output => format!(" | output {}", output)
+        };
+
+        format!("step {} | A={} B={} C={} | {}{}", self.steps, self.cpu.register_a, self.cpu.register_b, self.cpu.register_c, next, output)
+    }
+
+    fn list(&self) -> String {
+        crate::assembly::disassemble(&self.cpu.instructions).instructions.iter()
+            .map(|i| {
+                let next = if i.address as i64 == self.cpu.instruction_pointer { '>' } else { ' ' };
+                let breakpoint = if self.breakpoints.contains(&i.address) { '*' } else { ' ' };
+                format!("{}{}{:>3}: {}", next, breakpoint, i.address, i)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Input;
+
+    fn debugger() -> Debugger {
+        Debugger::new(&crate::parse(&Input::from_file("test-input").unwrap()).unwrap())
+    }
+
+    fn execute(debugger: &mut Debugger, command: &str) -> String {
+        debugger.execute(&command.parse::<Command>().unwrap())
+    }
+
+    #[test]
+    fn test_step_and_rewind() {
+        let mut debugger = debugger();
+
+        assert_eq!(execute(&mut debugger, "step 2"), "step 2 | A=364 B=0 C=0 | next 4: jnz 0 | output 4");
+        assert_eq!(execute(&mut debugger, "s"), "step 3 | A=364 B=0 C=0 | next 0: adv A>>1 | output 4");
+        assert_eq!(execute(&mut debugger, "rewind 2"), "step 1 | A=364 B=0 C=0 | next 2: out A%8");
+        assert!(execute(&mut debugger, "rewind 5").starts_with("can rewind at most 1"));
+        assert_eq!(execute(&mut debugger, "rewind 0"), "step 1 | A=364 B=0 C=0 | next 2: out A%8");
+
+        assert!(execute(&mut debugger, "continue").starts_with("the cpu halted\n"));
+        assert_eq!(execute(&mut debugger, "o"), "output: 4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_breakpoints_and_watches() {
+        let mut debugger = debugger();
+
+        execute(&mut debugger, "break 4");
+        assert!(execute(&mut debugger, "c").starts_with("breakpoint at 4\n"));
+        assert!(execute(&mut debugger, "l").contains(">*  4: jnz 0"));
+
+        execute(&mut debugger, "delete 4");
+        execute(&mut debugger, "watch A");
+        assert!(execute(&mut debugger, "c").starts_with("A changed from 364 to 182\n"));
+
+        execute(&mut debugger, "unwatch A");
+        execute(&mut debugger, "set A 8");
+        assert!(execute(&mut debugger, "c").ends_with("output 4,0,4,2,1,0"));
+
+        // the register change can be undone as well
+        execute(&mut debugger, "reset");
+        execute(&mut debugger, "set A 1");
+        assert!(execute(&mut debugger, "r").starts_with("step 0 | A=729"));
+    }
+
+    #[test]
+    fn test_commands() {
+        assert_eq!("set b 5".parse::<Command>(), Ok(Command::Set(Register::B, 5)));
+        assert_eq!("s".parse::<Command>(), Ok(Command::Step(1)));
+        assert!("break".parse::<Command>().is_err());
+        assert!("watch D".parse::<Command>().is_err());
+        assert!("jump 3".parse::<Command>().is_err());
+
+        let cpu = crate::parse(&Input::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7")).unwrap();
+        let mut debugger = Debugger::new(&cpu);
+        assert!(execute(&mut debugger, "s").starts_with("address 0: combo operand 7 is reserved\n"));
+    }
+}
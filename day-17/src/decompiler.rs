@@ -0,0 +1,357 @@
+use std::fmt::{Display, Formatter};
+use crate::debugger::Register;
+use crate::{divide_by_power_of_two, Cpu, Operation};
+
+// a value computed by the program, in terms of the registers at the start of a loop iteration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(i64),
+    Register(Register),
+    // x >> y, the division of adv, bdv and cdv
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Box<Expr>, Box<Expr>),
+    // x & mask, e.g. the %8 of bst and out
+    And(Box<Expr>, i64)
+}
+
+impl Expr {
+    // None where the cpu stops with an error (a negative exponent)
+    pub fn eval(&self, registers: &[i64; 3]) -> Option<i64> {
+        match self {
+            Expr::Const(c) => Some(*c),
+            Expr::Register(r) => Some(registers[*r as usize]),
+            Expr::Shr(x, y) => divide_by_power_of_two(x.eval(registers)?, y.eval(registers)?),
+            Expr::Xor(x, y) => Some(x.eval(registers)? ^ y.eval(registers)?),
+            Expr::And(x, mask) => Some(x.eval(registers)? & mask)
+        }
+    }
+
+    pub fn reads(&self, register: Register) -> bool {
+        match self {
+            Expr::Const(_) => false,
+            Expr::Register(r) => *r == register,
+            Expr::Shr(x, y) | Expr::Xor(x, y) => x.reads(register) || y.reads(register),
+            Expr::And(x, _) => x.reads(register)
+        }
+    }
+
+    // how many of the lowest bits of A the lowest `width` bits of the value depend on,
+    // None if that isn't bounded (e.g. A >> A)
+    pub fn reach(&self, width: u32) -> Option<u32> {
+        match self {
+            Expr::Const(_) | Expr::Register(Register::B | Register::C) => Some(0),
+            Expr::Register(Register::A) => Some(width),
+            Expr::Shr(x, y) => {
+                let distance = u32::try_from(y.max_value()?).ok()?;
+                Some(x.reach(width.checked_add(distance)?)?.max(y.reach(u32::BITS)?))
+            },
+            Expr::Xor(x, y) => Some(x.reach(width)?.max(y.reach(width)?)),
+            Expr::And(x, mask) => x.reach(width.min(bit_length(*mask)))
+        }
+    }
+
+    // None if the value isn't bounded by constants
+    fn max_value(&self) -> Option<i64> {
+        match self {
+            Expr::Const(c) => Some(*c),
+            Expr::Register(_) => None,
+            Expr::Shr(x, _) => x.max_value(),
+            Expr::Xor(x, y) => {
+                let bits = bit_length(x.max_value()?.max(y.max_value()?));
+                Some((1i64 << bits) - 1)
+            },
+            Expr::And(_, mask) => Some(*mask)
+        }
+    }
+
+    fn constant(&self) -> Option<i64> {
+        match self {
+            Expr::Const(c) => Some(*c),
+            _ => None
+        }
+    }
+
+    // the constructors fold constants, so the formulas stay readable
+
+    fn shr(x: Expr, y: Expr) -> Expr {
+        match (x, y) {
+            (x, Expr::Const(0)) => x,
+            (Expr::Const(c), Expr::Const(k)) if k > 0 => Expr::Const(divide_by_power_of_two(c, k).unwrap()),
+            (Expr::Shr(x, k), Expr::Const(l)) if k.constant().is_some_and(|k| k > 0) && l > 0 => {
+                Expr::shr(*x, Expr::Const(k.constant().unwrap().saturating_add(l)))
+            },
+            (x, y) => Expr::Shr(Box::new(x), Box::new(y))
+        }
+    }
+
+    fn xor(x: Expr, y: Expr) -> Expr {
+        match (x, y) {
+            (Expr::Const(a), Expr::Const(b)) => Expr::Const(a ^ b),
+            (x, Expr::Const(0)) | (Expr::Const(0), x) => x,
+            (Expr::Const(c), x) => Expr::xor(x, Expr::Const(c)),
+            (Expr::Xor(x, k), Expr::Const(l)) if k.constant().is_some() => {
+                Expr::xor(*x, Expr::Const(k.constant().unwrap() ^ l))
+            },
+            (x, y) => Expr::Xor(Box::new(x), Box::new(y))
+        }
+    }
+
+    fn and(x: Expr, mask: i64) -> Expr {
+        match x {
+            Expr::Const(c) => Expr::Const(c & mask),
+            Expr::And(x, m) => Expr::and(*x, m & mask),
+            x => Expr::And(Box::new(x), mask)
+        }
+    }
+}
+
+// e.g. `(A & 7) ^ 1` or `A >> ((A & 7) ^ 1)`
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operand = |x: &Expr| match x {
+            Expr::Const(_) | Expr::Register(_) => x.to_string(),
+            x => format!("({})", x)
+        };
+
+        match self {
+            Expr::Const(c) => write!(f, "{}", c),
+            Expr::Register(r) => write!(f, "{:?}", r),
+            Expr::Shr(x, y) => write!(f, "{} >> {}", operand(x), operand(y)),
+            Expr::Xor(x, y) => write!(f, "{} ^ {}", operand(x), operand(y)),
+            Expr::And(x, mask) => write!(f, "{} & {}", operand(x), mask)
+        }
+    }
+}
+
+fn bit_length(value: i64) -> u32 {
+    i64::BITS - value.leading_zeros()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub address: usize,
+    // always masked to 0-7
+    pub expression: Expr
+}
+
+// a program which repeats its instructions until A is 0, shifting A right by `shift` bits per
+// iteration. Each iteration writes the outputs, which only depend on A at its start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    pub shift: u32,
+    pub outputs: Vec<Output>
+}
+
+impl Loop {
+    // the outputs of the iteration which starts with A = `a`, None if the cpu would stop
+    pub fn iteration(&self, a: i64) -> Option<Vec<i64>> {
+        // the outputs don't read B and C
+        self.outputs.iter().map(|o| o.expression.eval(&[a, 0, 0])).collect()
+    }
+
+    // The lowest positive A for which the loop writes `output`. Iteration i starts with A >> (i * shift),
+    // so the last iteration only sees the highest bits of A. Build A from the last iteration backwards:
+    // extend each candidate by `shift` bits and keep those for which the iteration writes its part of the
+    // output. The outputs may also read higher bits, those are already known.
+    pub fn reverse(&self, output: &[i64]) -> Option<i64> {
+        let per_iteration = self.outputs.len();
+        if !output.len().is_multiple_of(per_iteration) {
+            return None;
+        }
+        let iterations = output.len() / per_iteration;
+
+        let mut candidates: Vec<i64> = vec![0];
+        for iteration in (0..iterations).rev() {
+            let expected = &output[iteration * per_iteration..(iteration + 1) * per_iteration];
+            let last = iteration == iterations - 1;
+
+            candidates = candidates.iter()
+                // the loop only continues with a non-zero A
+                .filter(|c| last || **c != 0)
+                .flat_map(|c| (0..1i64 << self.shift).map(move |bits| (c << self.shift) | bits))
+                .filter(|a| self.iteration(*a).as_deref() == Some(expected))
+                .collect();
+        }
+
+        candidates.into_iter().filter(|a| *a > 0).min()
+    }
+}
+
+// e.g.
+// loop until A is 0, A >>= 3 per iteration
+//   2: out (A >> 3) & 7  ; the lowest 6 bits of A
+impl Display for Loop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "loop until A is 0, A >>= {} per iteration", self.shift)?;
+        for output in &self.outputs {
+            let reach = match output.expression.reach(3) {
+                Some(bits) => format!("the lowest {} bits of A", bits),
+                None => String::from("all bits of A")
+            };
+            writeln!(f, "{:>3}: out {}  ; {}", output.address, output.expression, reach)?;
+        }
+
+        Ok(())
+    }
+}
+
+// why a program can't be written as a single loop
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecompileError {
+    // the last instruction isn't `jnz 0`
+    NoLoop,
+    // any jump but the last one
+    Jump { address: usize },
+    InvalidOperand { address: usize, operand: i64 },
+    // A isn't shifted right by a constant number of bits, so the loop may not end
+    NoShift,
+    // `Loop::reverse` tries 2^shift bits per iteration, too many for a shift above `MAX_SHIFT`
+    LargeShift { shift: i64 },
+    NoOutput,
+    // the output depends on B or C of the previous iteration
+    Carried { address: usize, register: Register }
+}
+
+impl Display for DecompileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecompileError::NoLoop => write!(f, "the program doesn't end with 'jnz 0'"),
+            DecompileError::Jump { address } => write!(f, "address {}: only the last instruction may jump", address),
+            DecompileError::InvalidOperand { address, operand } => write!(f, "address {}: combo operand {} is reserved", address, operand),
+            DecompileError::NoShift => write!(f, "register A isn't shifted right by a constant per iteration"),
+            DecompileError::LargeShift { shift } => write!(f, "register A is shifted right by {} bits, at most {} are supported", shift, MAX_SHIFT),
+            DecompileError::NoOutput => write!(f, "the loop has no output"),
+            DecompileError::Carried { address, register } => {
+                write!(f, "address {}: the output depends on {:?} of the previous iteration", address, register)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecompileError {}
+
+// the largest shift per iteration `Loop::reverse` solves
+pub const MAX_SHIFT: u32 = 16;
+
+// executes the loop body once with symbolic registers
+pub fn decompile(cpu: &Cpu) -> Result<Loop, DecompileError> {
+    let program = &cpu.instructions;
+    let end = program.len().checked_sub(2).ok_or(DecompileError::NoLoop)?;
+    if program[end..] != [3, 0] {
+        return Err(DecompileError::NoLoop);
+    }
+
+    let mut registers = [Register::A, Register::B, Register::C].map(Expr::Register);
+    let mut outputs: Vec<Output> = vec![];
+    for address in (0..end).step_by(2) {
+        let combo = |operand: i64, registers: &[Expr; 3]| match operand {
+            0..=3 => Ok(Expr::Const(operand)),
+            4..=6 => Ok(registers[operand as usize - 4].clone()),
+            _ => Err(DecompileError::InvalidOperand { address, operand })
+        };
+
+        match Operation::from(program[address], program[address + 1]) {
+            Operation::Adv(operand) => registers[0] = Expr::shr(registers[0].clone(), combo(operand, &registers)?),
+            Operation::Bxl(literal) => registers[1] = Expr::xor(registers[1].clone(), Expr::Const(literal)),
+            Operation::Bst(operand) => registers[1] = Expr::and(combo(operand, &registers)?, 7),
+            Operation::Jnz(_) => return Err(DecompileError::Jump { address }),
+            Operation::Bxc => registers[1] = Expr::xor(registers[1].clone(), registers[2].clone()),
+            Operation::Out(operand) => outputs.push(Output { address, expression: Expr::and(combo(operand, &registers)?, 7) }),
+            Operation::Bdv(operand) => registers[1] = Expr::shr(registers[0].clone(), combo(operand, &registers)?),
+            Operation::Cdv(operand) => registers[2] = Expr::shr(registers[0].clone(), combo(operand, &registers)?)
+        }
+    }
+
+    let shift = match &registers[0] {
+        Expr::Shr(x, y) if **x == Expr::Register(Register::A) => match **y {
+            Expr::Const(shift) if shift > i64::from(MAX_SHIFT) => return Err(DecompileError::LargeShift { shift }),
+            Expr::Const(shift) if shift > 0 => shift as u32,
+            _ => return Err(DecompileError::NoShift)
+        },
+        _ => return Err(DecompileError::NoShift)
+    };
+    if outputs.is_empty() {
+        return Err(DecompileError::NoOutput);
+    }
+    for output in &outputs {
+        if let Some(register) = [Register::B, Register::C].into_iter().find(|r| output.expression.reads(*r)) {
+            return Err(DecompileError::Carried { address: output.address, register });
+        }
+    }
+
+    Ok(Loop { shift, outputs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu;
+
+    // bst A, bxl 1, cdv B, bxl 5, bxc, out B, adv 3, jnz 0, like the puzzle inputs
+    const PROGRAM: &str = "2,4,1,1,7,5,1,5,4,0,5,5,0,3,3,0";
+
+    #[test]
+    fn test_decompile() {
+        let program_loop = decompile(&cpu(2024, "0,3,5,4,3,0")).unwrap();
+        assert_eq!(program_loop.to_string(), "loop until A is 0, A >>= 3 per iteration\n  2: out (A >> 3) & 7  ; the lowest 6 bits of A\n");
+
+        let program_loop = decompile(&cpu(0, PROGRAM)).unwrap();
+        assert_eq!(program_loop.shift, 3);
+        assert_eq!(program_loop.outputs[0].expression.to_string(), "(((A & 7) ^ 4) ^ (A >> ((A & 7) ^ 1))) & 7");
+        assert_eq!(program_loop.outputs[0].expression.reach(3), Some(10));
+    }
+
+    // the output of the cpu and of the decompiled loop, starting with A = `a`
+    fn outputs(initial_cpu: &Cpu, a: i64) -> (String, String) {
+        let program_loop = decompile(initial_cpu).unwrap();
+        let mut cpu = initial_cpu.clone();
+        cpu.register_a = a;
+
+        let mut output: Vec<i64> = vec![];
+        let mut register_a = a;
+        while register_a != 0 {
+            output.extend(program_loop.iteration(register_a).unwrap());
+            register_a >>= program_loop.shift;
+        }
+
+        (cpu.run(crate::STEP_LIMIT).unwrap(), output.iter().map(|o| o.to_string()).collect::<Vec<String>>().join(","))
+    }
+
+    #[test]
+    fn test_matches_cpu() {
+        let initial_cpu = cpu(0, PROGRAM);
+        for a in 1..5000 {
+            let (cpu, decompiled) = outputs(&initial_cpu, a);
+            assert_eq!(cpu, decompiled);
+        }
+
+        // cdv A, out C, adv 3, jnz 0: A >> A is 0 once A has 64 bits or more, in both
+        let initial_cpu = cpu(0, "7,4,5,6,0,3,3,0");
+        for a in (1..200).chain([1 << 40, i64::MAX]) {
+            let (cpu, decompiled) = outputs(&initial_cpu, a);
+            assert_eq!(cpu, decompiled);
+        }
+    }
+
+    #[test]
+    fn test_reverse() {
+        let cpu = cpu(0, PROGRAM);
+        let a = decompile(&cpu).unwrap().reverse(&cpu.instructions).unwrap();
+
+        let mut quine = cpu.clone();
+        quine.register_a = a;
+        assert_eq!(quine.run(crate::STEP_LIMIT).unwrap(), PROGRAM);
+        assert_eq!(Some(a), crate::search_register_a(&cpu));
+    }
+
+    #[test]
+    fn test_decompile_errors() {
+        assert_eq!(decompile(&cpu(0, "0,3,5,4")), Err(DecompileError::NoLoop));
+        assert_eq!(decompile(&cpu(0, "3,4,3,0")), Err(DecompileError::Jump { address: 0 }));
+        assert_eq!(decompile(&cpu(0, "5,7,0,3,3,0")), Err(DecompileError::InvalidOperand { address: 0, operand: 7 }));
+        assert_eq!(decompile(&cpu(0, "0,4,5,4,3,0")), Err(DecompileError::NoShift));
+        assert_eq!(decompile(&cpu(0, "0,3,3,0")), Err(DecompileError::NoOutput));
+        assert_eq!(decompile(&cpu(0, "0,3,0,3,0,3,0,3,0,3,0,3,5,4,3,0")), Err(DecompileError::LargeShift { shift: 18 }));
+        assert_eq!(decompile(&cpu(0, "5,5,0,3,3,0")), Err(DecompileError::Carried { address: 0, register: Register::B }));
+    }
+}
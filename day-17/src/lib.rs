@@ -0,0 +1,383 @@
+use std::fmt::{Display, Formatter};
+use std::ops::BitXor;
+use regex::Regex;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+pub mod assembly;
+pub mod debugger;
+pub mod decompiler;
+
+pub fn parse(input: &Input) -> Result<Cpu, ParseError> {
+    Cpu::try_from(input)
+}
+
+// instructions a program may execute before it's considered to loop forever
+pub const STEP_LIMIT: usize = 1_000_000;
+
+pub fn part1(cpu: &Cpu) -> Answer {
+    let mut cpu = cpu.clone();
+
+    match cpu.run(STEP_LIMIT) {
+        Ok(output) => Answer::from(output),
+        Err(e) => Answer::NoSolution(e.to_string())
+    }
+}
+
+pub fn part2(cpu: &Cpu) -> Answer {
+    match find_register_a(cpu) {
+        Some(a) => Answer::from(a),
+        None => Answer::NoSolution(String::from("no value of register A reproduces the program"))
+    }
+}
+
+// a single loop is solved from the formulas of its outputs, see `Loop::reverse`.
+// Other programs are searched by running the cpu.
+fn find_register_a(initial_cpu: &Cpu) -> Option<i64> {
+    match decompiler::decompile(initial_cpu) {
+        Ok(program_loop) => program_loop.reverse(&initial_cpu.instructions),
+        Err(_) => search_register_a(initial_cpu)
+    }
+}
+
+// the program consumes register A three bits per output digit, the highest bits
+// producing the last digit. Build A from the last digit backwards: for each candidate
+// try all eight values of the next three bits and keep those for which the cpu
+// outputs the expected tail of the program. A candidate which makes the program fail is dropped.
+fn search_register_a(initial_cpu: &Cpu) -> Option<i64> {
+    let program = &initial_cpu.instructions;
+    let mut candidates: Vec<i64> = vec![0];
+
+    for digit in (0..program.len()).rev() {
+        let expected_output = program[digit..].iter().map(|i| i.to_string()).collect::<Vec<String>>().join(",");
+
+        candidates = candidates.iter()
+            .flat_map(|c| (0..8).map(move |bits| c * 8 + bits))
+            .filter(|a| {
+                let mut cpu = initial_cpu.clone();
+                cpu.register_a = *a;
+
+                cpu.run(STEP_LIMIT).is_ok_and(|output| output == expected_output)
+            })
+            .collect();
+    }
+
+    candidates.into_iter().filter(|a| *a > 0).min()
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    register_a: i64,
+    register_b: i64,
+    register_c: i64,
+
+    instruction_pointer: i64,
+    instructions: Vec<i64>,
+
+    output_buffer: Vec<i64>
+}
+
+impl TryFrom<&Input> for Cpu {
+    type Error = ParseError;
+
+    fn try_from(input: &Input) -> Result<Self, Self::Error> {
+        let mut sections = input.numbered_sections().into_iter();
+        let (registers_line, registers) = sections.next()
+            .ok_or_else(|| ParseError::new(0, "registers", "").at_line(1))?;
+        let (program_line, program) = sections.next()
+            .ok_or_else(|| ParseError::new(0, "a program", "").at_line(input.lines().len() + 1))?;
+
+        let mut values: Vec<i64> = vec![];
+        for (index, name) in ["A", "B", "C"].iter().enumerate() {
+            // e.g.: Register A: 729
+            let line = registers.get(index)
+                .ok_or_else(|| ParseError::new(0, &format!("register {}", name), "").at_line(registers_line + index))?;
+            let register_regex = Regex::new(&format!(r"^Register {}: (\d+)$", name)).unwrap();
+            let value = register_regex.captures(line)
+                .ok_or_else(|| ParseError::new(1, &format!("'Register {}: <n>'", name), line))
+                .and_then(|c| {
+                    let m = c.get(1).unwrap();
+                    parse::number::<i64>(m.as_str(), m.start() + 1)
+                })
+                .map_err(|e| e.at_line(registers_line + index))?;
+            values.push(value);
+        }
+
+        let instructions = parse_program(&program[0]).map_err(|e| e.at_line(program_line))?;
+        let output_buffer: Vec<i64> = vec![];
+
+        Ok(Cpu { register_a: values[0], register_b: values[1], register_c: values[2], instructions, instruction_pointer: 0, output_buffer })
+    }
+}
+
+// the values of the `Program:` line, also of programs the cpu can't run (e.g. with an odd length)
+pub fn read_program(input: &Input) -> Result<Vec<i64>, ParseError> {
+    let (index, line) = input.lines().iter().enumerate()
+        .find(|(_, l)| l.starts_with("Program:"))
+        .ok_or_else(|| ParseError::new(0, "a line 'Program: <n>,<n>,...'", "").at_line(input.lines().len() + 1))?;
+
+    parse_values(line).map_err(|e| e.at_line(index + 1))
+}
+
+// every instruction is an opcode followed by its operand
+fn parse_program(line: &str) -> Result<Vec<i64>, ParseError> {
+    let instructions = parse_values(line)?;
+    if !instructions.len().is_multiple_of(2) {
+        return Err(ParseError::new(line.len() + 1, "an operand for the last opcode", ""));
+    }
+
+    Ok(instructions)
+}
+
+// e.g.: Program: 0,1,5,4,3,0
+// all values are 3 bit numbers
+fn parse_values(line: &str) -> Result<Vec<i64>, ParseError> {
+    let prefix = "Program: ";
+    let values = line.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(1, "'Program: <n>,<n>,...'", line))?;
+
+    let mut instructions: Vec<i64> = vec![];
+    let mut column = prefix.len() + 1;
+    for token in values.split(",") {
+        let value = parse::number::<i64>(token, column)?;
+        if !(0..8).contains(&value) {
+            return Err(ParseError::new(column, "a 3 bit number (0-7)", token));
+        }
+        instructions.push(value);
+        column += token.len() + 1;
+    }
+
+    Ok(instructions)
+}
+
+// why the cpu stopped before the program halted. There is no overflow: a division by a
+// power of 2 too large for 64 bits is 0 (see `divide_by_power_of_two`), bxl, bxc and bst
+// only combine and truncate bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CpuError {
+    // a division by 2^exponent with a negative exponent (from a negative register)
+    NegativeExponent { address: usize, exponent: i64 },
+    InvalidOperand { address: usize, operand: i64 },
+    // a jump to the last value of the program leaves its opcode without an operand
+    TruncatedInstruction { address: usize },
+    StepLimitExceeded { limit: usize }
+}
+
+impl Display for CpuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpuError::NegativeExponent { address, exponent } => write!(f, "address {}: 2^{} is not an integer", address, exponent),
+            CpuError::InvalidOperand { address, operand } => write!(f, "address {}: combo operand {} is reserved", address, operand),
+            CpuError::TruncatedInstruction { address } => write!(f, "address {}: the opcode has no operand", address),
+            CpuError::StepLimitExceeded { limit } => write!(f, "the program didn't halt within {} steps", limit)
+        }
+    }
+}
+
+impl std::error::Error for CpuError {}
+
+impl Cpu {
+    // executes the program until it halts, at most `step_limit` instructions. Returns the output.
+    pub fn run(&mut self, step_limit: usize) -> Result<String, CpuError> {
+        for _ in 0..step_limit {
+            if self.tick()? {
+                return Ok(self.read_output());
+            }
+        }
+
+        match self.halted() {
+            true => Ok(self.read_output()),
+            false => Err(CpuError::StepLimitExceeded { limit: step_limit })
+        }
+    }
+
+    // executes a single instruction, Ok(true) if the program already halted.
+    // A failed instruction doesn't change the cpu.
+    pub fn tick(&mut self) -> Result<bool, CpuError> {
+        if self.halted() {
+            return Ok(true);
+        }
+        let address = self.instruction_pointer as usize;
+        let op_code = self.instructions[address];
+        let operand = *self.instructions.get(address + 1).ok_or(CpuError::TruncatedInstruction { address })?;
+        let op = Operation::from(
+            op_code,
+            operand
+        );
+        Operation::execute(op, self)?;
+
+        Ok(false) // program continues
+    }
+
+    pub fn halted(&self) -> bool {
+        self.instruction_pointer as usize >= self.instructions.len()
+    }
+
+    pub fn read_output(&self) -> String {
+        let out_as_str: Vec<String> = self.output_buffer.iter().map(|o| o.to_string()).collect();
+        out_as_str.join(",")
+    }
+}
+
+enum Operation {
+    Adv(i64),
+    Bxl(i64),
+    Bst(i64),
+    Jnz(i64),
+    Bxc,
+    Out(i64),
+    Bdv(i64),
+    Cdv(i64)
+}
+
+impl Operation {
+    pub fn from(op_code: i64, operand: i64) -> Self {
+        match op_code {
+            0 => Operation::Adv(operand),
+            1 => Operation::Bxl(operand),
+            2 => Operation::Bst(operand),
+            3 => Operation::Jnz(operand),
+            4 => Operation::Bxc,
+            5 => Operation::Out(operand),
+            6 => Operation::Bdv(operand),
+            7 => Operation::Cdv(operand),
+            _ => { panic!("Unknown opcode {} with operand {}", op_code, operand); }
+        }
+    }
+
+    pub fn execute(operation: Operation, cpu: &mut Cpu) -> Result<(), CpuError> {
+        match operation {
+            Operation::Adv(combo_operand) => {
+                cpu.register_a = Operation::divide(combo_operand, cpu)?;
+            }
+            Operation::Bxl(literal_operand) => {
+                let result = cpu.register_b.bitxor(literal_operand);
+                cpu.register_b = result;
+            }
+            Operation::Bst(combo_operand) => {
+                cpu.register_b = Operation::translate_combo_operand(combo_operand, cpu)? % 8;
+            }
+            Operation::Jnz(literal_operand) => {
+                if cpu.register_a != 0 {
+                    cpu.instruction_pointer = literal_operand;
+                } else {
+                    cpu.instruction_pointer += 2; // override maybe the later default increasing
+                }
+            }
+            Operation::Bxc => {
+                let result = cpu.register_b.bitxor(cpu.register_c);
+                cpu.register_b = result;
+            }
+            Operation::Out(combo_operand) => {
+                let result = Operation::translate_combo_operand(combo_operand, cpu)? % 8;
+                cpu.output_buffer.push(result);
+            }
+            Operation::Bdv(combo_operand) => {
+                cpu.register_b = Operation::divide(combo_operand, cpu)?;
+            }
+            Operation::Cdv(combo_operand) => {
+                cpu.register_c = Operation::divide(combo_operand, cpu)?;
+            }
+        }
+
+        // forward instruction counter
+        match operation {
+            Operation::Jnz(_) => {}, // no instruction increase
+            _ => { cpu.instruction_pointer += 2 } // all others: increase by two
+        }
+
+        Ok(())
+    }
+
+    // register A divided by 2^operand, for adv, bdv and cdv
+    fn divide(combo_operand: i64, cpu: &Cpu) -> Result<i64, CpuError> {
+        let exponent = Operation::translate_combo_operand(combo_operand, cpu)?;
+
+        divide_by_power_of_two(cpu.register_a, exponent)
+            .ok_or(CpuError::NegativeExponent { address: cpu.instruction_pointer as usize, exponent })
+    }
+
+    fn translate_combo_operand(operand: i64, cpu: &Cpu) -> Result<i64, CpuError> {
+        match operand {
+            0 => Ok(0),
+            1 => Ok(1),
+            2 => Ok(2),
+            3 => Ok(3),
+            4 => Ok(cpu.register_a),
+            5 => Ok(cpu.register_b),
+            6 => Ok(cpu.register_c),
+            _ => Err(CpuError::InvalidOperand { address: cpu.instruction_pointer as usize, operand })
+        }
+    }
+}
+
+// numerator / 2^exponent, rounded towards zero like the division of the cpu. 2^exponent
+// doesn't fit into 64 bits for large exponents, but the quotient is 0 then, so the division
+// can't overflow. None for a negative exponent. The decompiler evaluates its formulas with it,
+// so they agree with the cpu.
+fn divide_by_power_of_two(numerator: i64, exponent: i64) -> Option<i64> {
+    match exponent {
+        ..0 => None,
+        0..64 => Some((numerator as i128 / (1i128 << exponent)) as i64),
+        _ => Some(0)
+    }
+}
+
+// a cpu with `register_a` and the program, B and C are 0
+#[cfg(test)]
+pub(crate) fn cpu(register_a: i64, program: &str) -> Cpu {
+    parse(&Input::parse(&format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}", register_a, program))).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+
+        assert_eq!(result, 117440);
+
+        // 22 times adv 3, too large a shift for the decompiler, falls back to the search
+        let program = format!("{}5,4,3,0", "0,3,".repeat(22));
+        assert_eq!(part2(&cpu(0, &program)), Answer::NoSolution(String::from("no value of register A reproduces the program")));
+    }
+
+    #[test]
+    fn test_read_program() {
+        let input = Input::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5");
+
+        assert_eq!(read_program(&input).unwrap(), vec![0, 1, 5]);
+        assert_eq!(parse(&input).unwrap_err().line, 5);
+    }
+
+    #[test]
+    fn test_cpu_errors() {
+        assert_eq!(cpu(729, "0,1,5,4,3,0").run(STEP_LIMIT), Ok(String::from("4,6,3,5,6,3,5,2,1,0")));
+
+        assert_eq!(cpu(1, "3,0").run(100), Err(CpuError::StepLimitExceeded { limit: 100 }));
+        assert_eq!(cpu(1, "5,4,5,7").run(100), Err(CpuError::InvalidOperand { address: 2, operand: 7 }));
+        // A / 2^100 is 0
+        assert_eq!(cpu(100, "0,4,5,4").run(100), Ok(String::from("0")));
+        let mut negative = cpu(1, "0,5");
+        negative.register_b = -1;
+        assert_eq!(negative.run(100), Err(CpuError::NegativeExponent { address: 0, exponent: -1 }));
+        // jnz 1 jumps to the operand, which leaves bxl without one
+        assert_eq!(cpu(1, "3,1").run(100), Err(CpuError::TruncatedInstruction { address: 1 }));
+
+        assert_eq!(part1(&cpu(1, "3,0")), Answer::NoSolution(String::from("the program didn't halt within 1000000 steps")));
+
+        // the failed instruction didn't change the cpu
+        let mut cpu = cpu(1, "5,4,5,7");
+        assert!(cpu.run(100).is_err());
+        assert_eq!((cpu.instruction_pointer, cpu.read_output()), (2, String::from("1")));
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_common::search::bfs;
+use aoc_common::parse;
+use aoc_common::{Answer, Grid, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Memory, ParseError> {
+    parse_with(input, (70, 70), 1024)
+}
+
+pub fn parse_with(input: &Input, end_point: (i64, i64), bytes_fallen: usize) -> Result<Memory, ParseError> {
+    let lines = input.lines();
+    let corrupted_bytes = lines.iter().enumerate().map(|(index, s)| {
+        parse_byte(s).map_err(|e| e.at_line(index + 1))
+    }).collect::<Result<Vec<(i64, i64)>, ParseError>>()?;
+
+    Ok(Memory { end_point, bytes_fallen, corrupted_bytes })
+}
+
+// e.g.: 5,4
+fn parse_byte(s: &str) -> Result<(i64, i64), ParseError> {
+    let ((x, x_column), (y, y_column)) = parse::split_once(s, ",", 1)?;
+    let x = parse::number::<i64>(x, x_column)?;
+    let y = parse::number::<i64>(y, y_column)?;
+
+    Ok((x, y))
+}
+
+pub fn part1(memory: &Memory) -> Answer {
+    let Some(corrupted_bytes_at_time) = memory.corrupted_bytes.get(0..memory.bytes_fallen) else {
+        return Answer::NoSolution(format!("only {} bytes fall, part 1 needs {}", memory.corrupted_bytes.len(), memory.bytes_fallen));
+    };
+
+    match shortest_path(memory.end_point, &corrupt_memory(memory.end_point, corrupted_bytes_at_time)) {
+        Some(steps) => Answer::from(steps),
+        None => Answer::NoSolution(String::from("the exit isn't reachable"))
+    }
+}
+
+pub fn part2(memory: &Memory) -> Answer {
+    for bytes_fallen in 1..=memory.corrupted_bytes.len() {
+        let corrupted_bytes_at_time: &[(i64, i64)] = memory.corrupted_bytes.get(0..bytes_fallen).unwrap();
+        let reachable = is_reachable(memory.end_point, &corrupt_memory(memory.end_point, corrupted_bytes_at_time));
+        if !reachable {
+            let coordinates = corrupted_bytes_at_time.last().unwrap(); // last failing byte
+            return Answer::from(*coordinates);
+        }
+    }
+
+    Answer::NoSolution(String::from("the exit is still reachable after all bytes fell"))
+}
+
+pub struct Memory {
+    end_point: (i64, i64),
+    bytes_fallen: usize, // number of bytes which have fallen for part 1
+    corrupted_bytes: Vec<(i64, i64)>
+}
+
+// None if the exit isn't reachable
+fn shortest_path(end_point: (i64, i64), memory: &Grid<bool>) -> Option<usize> {
+    let result = bfs((0, 0), |p| get_neighbours(*p, memory), |p| *p == end_point);
+
+    result.cost().map(|cost| cost as usize)
+}
+
+fn is_reachable(end_point: (i64, i64), memory: &Grid<bool>) -> bool {
+    let result = bfs((0, 0), |p| get_neighbours(*p, memory), |p| *p == end_point);
+
+    result.goal().is_some()
+}
+
+// memory cells are true if a byte has fallen onto them, bytes outside of the memory are ignored
+fn corrupt_memory(end_point: (i64, i64), corrupted_bytes: &[(i64, i64)]) -> Grid<bool> {
+    let mut memory = Grid::new(end_point.0 as usize + 1, end_point.1 as usize + 1, false);
+    corrupted_bytes.iter().for_each(|b| {
+        memory.set(*b, true);
+    });
+
+    memory
+}
+
+fn get_neighbours(p: (i64, i64), memory: &Grid<bool>) -> Vec<(i64, i64)> {
+    let real_neighbours: Vec<(i64, i64)> = memory.neighbours4(p)
+        .filter(|n| !memory[*n])
+        .collect();
+
+    real_neighbours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse_with(&Input::from_file("test-input").unwrap(), (6, 6), 12).unwrap());
+
+        assert_eq!(result, 22);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse_with(&Input::from_file("test-input").unwrap(), (6, 6), 12).unwrap());
+
+        assert_eq!(result, "6,1");
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let input = Input::from_file("test-input").unwrap();
+
+        assert_eq!(part1(&parse(&input).unwrap()), Answer::NoSolution(String::from("only 25 bytes fall, part 1 needs 1024")));
+        // (1,0) and (0,1) block the start
+        let blocked = parse_with(&Input::parse("1,0\n0,1"), (6, 6), 2).unwrap();
+        assert_eq!(part1(&blocked), Answer::NoSolution(String::from("the exit isn't reachable")));
+        assert_eq!(part2(&blocked), "0,1");
+        assert_eq!(part2(&parse_with(&Input::parse("1,1"), (6, 6), 1).unwrap()), Answer::NoSolution(String::from("the exit is still reachable after all bytes fell")));
+    }
+
+    #[test]
+    fn test_bytes_outside_of_memory() {
+        let memory = corrupt_memory((2, 2), &[(1, 1), (3, 0), (-1, 2)]);
+
+        assert_eq!(memory.find_all(|corrupted| *corrupted), vec![(1, 1)]);
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use aoc_common::{Answer, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Onsen, ParseError> {
+    let mut sections = input.numbered_sections().into_iter();
+    let (towels_line, towels) = sections.next()
+        .ok_or_else(|| ParseError::new(0, "towels", "").at_line(1))?;
+    let (patterns_line, patterns) = sections.next()
+        .ok_or_else(|| ParseError::new(0, "patterns", "").at_line(input.lines().len() + 1))?;
+
+    // e.g.: r, wr, b, g, bwu, rb, gb, br
+    let mut all_towels: Vec<Towel> = vec![];
+    let mut column = 1;
+    for token in towels[0].split(", ") {
+        let towel = Towel::from(colours(token, column).map_err(|e| e.at_line(towels_line))?);
+        all_towels.push(towel);
+        column += token.len() + 2;
+    }
+
+    let all_patterns = patterns.iter().enumerate()
+        .map(|(index, p)| p.parse::<ColourPattern>().map_err(|e| e.at_line(patterns_line + index)))
+        .collect::<Result<Vec<ColourPattern>, ParseError>>()?;
+
+    Ok(Onsen::from(all_patterns, all_towels))
+}
+
+// colours of a token which starts at `column` of its line
+fn colours(s: &str, column: usize) -> Result<Vec<Colour>, ParseError> {
+    s.chars().enumerate()
+        .map(|(index, c)| Colour::try_from(c).map_err(|e| ParseError { column: column + index, ..e }))
+        .collect()
+}
+
+pub fn part1(onsen: &Onsen) -> Answer {
+    Answer::from(onsen.count_valid())
+}
+
+pub fn part2(onsen: &Onsen) -> Answer {
+    Answer::from(onsen.compute_all())
+}
+
+pub struct Onsen {
+    patterns: Vec<ColourPattern>,
+    towels: Vec<Towel>
+}
+
+impl Onsen {
+    fn from(patterns: Vec<ColourPattern>, towels: Vec<Towel>) -> Self {
+        Onsen { patterns, towels }
+    }
+
+    pub fn count_valid(&self) -> usize {
+        self.patterns.iter().filter(|p| {
+                p.is_valid(&self.towels)
+            })
+            .count()
+    }
+
+    pub fn compute_all(&self) -> usize {
+        let mut cache: HashMap<String, usize> = HashMap::new();
+
+        self.patterns.iter().fold(0, |r, p| {
+            r + p.total_number_of_patterns(&self.towels, &mut cache)
+        })
+    }
+}
+
+struct Towel {
+    colours: Vec<Colour>
+}
+
+impl Towel {
+    fn from(colours: Vec<Colour>) -> Self {
+        Towel { colours }
+    }
+}
+
+struct ColourPattern {
+    pattern: Vec<Colour>
+}
+
+impl FromStr for ColourPattern {
+    type Err = ParseError;
+
+    fn from_str(string_pattern: &str) -> Result<Self, Self::Err> {
+        let pattern = colours(string_pattern, 1)?;
+
+        Ok(ColourPattern { pattern })
+    }
+}
+
+impl ColourPattern {
+    pub fn is_valid(&self, towels: &Vec<Towel>) -> bool {
+        self.can_solve(towels, 0)
+    }
+
+    fn can_solve(&self, towels: &Vec<Towel>, start: usize) -> bool {
+        if start == self.pattern.len() {
+            return true;
+        }
+
+        // try to find a towel which starts with pattern, if so, forward the slice
+        let pattern = self.pattern.get(start..).unwrap();
+        let matching_towels: Vec<&Towel> = towels.iter().filter(|t| {
+            let towel_colours = t.colours.as_slice();
+            pattern.starts_with(towel_colours)
+        }).collect();
+
+        for t in matching_towels {
+            let r = self.can_solve(towels, start + t.colours.len());
+            if r {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn total_number_of_patterns(&self, towels: &Vec<Towel>, cache: &mut HashMap<String, usize>) -> usize {
+        self.patterns(towels, 0, cache)
+    }
+
+    fn patterns(&self, towels: &Vec<Towel>, start: usize, cache: &mut HashMap<String, usize>) -> usize {
+        if start == self.pattern.len() {
+            return 1;
+        }
+
+        // try to find a towel which starts with pattern, if so, forward the slice
+        let pattern = self.pattern.get(start..).unwrap();
+        // try search a result in the cache
+        let pattern_string = ColourPattern::create_string(pattern);
+        if let Some(count) = cache.get(&pattern_string) {
+            return *count;
+        }
+
+        // no cache hit, we need to calculate it
+        let matching_towels: Vec<&Towel> = towels.iter().filter(|t| {
+            let towel_colours = t.colours.as_slice();
+            pattern.starts_with(towel_colours)
+        }).collect();
+
+        let number_of_patterns = matching_towels.iter().fold(0, |r, t| {
+            r + self.patterns(towels, start + t.colours.len(), cache)
+        });
+        // cache it for later usage
+        cache.insert(pattern_string, number_of_patterns);
+
+        number_of_patterns
+    }
+
+    fn create_string(colours: &[Colour]) -> String {
+        String::from_iter(colours.iter().map(|c| c.as_char()))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Colour {
+    White, Blue, Black, Red, Green
+}
+
+impl TryFrom<char> for Colour {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'w' => Ok(Colour::White),
+            'u' => Ok(Colour::Blue),
+            'b' => Ok(Colour::Black),
+            'r' => Ok(Colour::Red),
+            'g' => Ok(Colour::Green),
+            _ => Err(ParseError::new(0, "a colour ('w', 'u', 'b', 'r' or 'g')", &c.to_string()))
+        }
+    }
+}
+
+impl Colour {
+    pub fn as_char(&self) -> char {
+        match self {
+            Colour::White => 'w',
+            Colour::Blue => 'u',
+            Colour::Black => 'b',
+            Colour::Red => 'r',
+            Colour::Green => 'g'
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 16);
+    }
+}
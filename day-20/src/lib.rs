@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use aoc_common::search::bfs;
+use aoc_common::{Answer, Coord, Grid, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Racetrack, ParseError> {
+    parse_with(input, 100)
+}
+
+// only cheats which save at least `min_saving` picoseconds are counted
+pub fn parse_with(input: &Input, min_saving: i64) -> Result<Racetrack, ParseError> {
+    let map = Map::try_from(&input.grid()?)?;
+
+    Ok(Racetrack { map, min_saving })
+}
+
+pub fn part1(racetrack: &Racetrack) -> Answer {
+    cheats(racetrack, 2)
+}
+
+pub fn part2(racetrack: &Racetrack) -> Answer {
+    cheats(racetrack, 20)
+}
+
+fn cheats(racetrack: &Racetrack, max_cheat_length: i64) -> Answer {
+    match racetrack.map.count_cheats(max_cheat_length, racetrack.min_saving) {
+        Some(count) => Answer::from(count),
+        None => Answer::NoSolution(String::from("there is no path from S to E"))
+    }
+}
+
+pub struct Racetrack {
+    map: Map,
+    min_saving: i64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Type {
+    Wall, Field
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    objects: Grid<Type>,
+    start: Coord,
+    end: Coord
+}
+
+impl TryFrom<&Grid<char>> for Map {
+    type Error = ParseError;
+
+    fn try_from(grid: &Grid<char>) -> Result<Self, Self::Error> {
+        let start = grid.find_char('S')
+            .ok_or_else(|| ParseError::new(0, "a start ('S')", "").at_line(1))?;
+        let end = grid.find_char('E')
+            .ok_or_else(|| ParseError::new(0, "an end ('E')", "").at_line(1))?;
+        let objects = grid.map(|c| if *c == '#' { Type::Wall } else { Type::Field });
+
+        Ok(Map { objects, start, end })
+    }
+}
+
+impl Map {
+    // a cheat from p to q (both on the track) replaces the normal path between them by
+    // their manhattan distance. With the distances from the start to p and from q to the
+    // end, the length of the cheated path is known without searching again.
+    // None if the end isn't reachable.
+    pub fn count_cheats(&self, max_cheat_length: i64, min_saving: i64) -> Option<usize> {
+        let from_start = self.distances(self.start);
+        let to_end = self.distances(self.end);
+        let default_distance = *from_start.get(&self.end)?;
+
+        let mut cheat_counter = 0;
+        for (p, p_distance) in &from_start {
+            for dy in -max_cheat_length..=max_cheat_length {
+                let remaining = max_cheat_length - dy.abs();
+                for dx in -remaining..=remaining {
+                    let q = (p.0 + dx, p.1 + dy);
+                    if let Some(q_distance) = to_end.get(&q) {
+                        let cheated_distance = p_distance + dx.abs() + dy.abs() + q_distance;
+                        if default_distance - cheated_distance >= min_saving {
+                            cheat_counter += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(cheat_counter)
+    }
+
+    // distance of every reachable field from `from`
+    fn distances(&self, from: Coord) -> HashMap<Coord, i64> {
+        let result = bfs(from, |c| self.get_neighbours(c), |_| false);
+
+        result.costs().clone()
+    }
+
+    fn get_neighbours(&self, f: &Coord) -> Vec<Coord> {
+        let real_neighbours: Vec<Coord> = self.objects.neighbours4(*f)
+            .filter(|c| self.objects[*c] == Type::Field)
+            .collect();
+
+        real_neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = Input::from_file("test-input").unwrap();
+
+        assert_eq!(part1(&parse_with(&input, 2).unwrap()), 44);
+        assert_eq!(part1(&parse_with(&input, 20).unwrap()), 5);
+        assert_eq!(part1(&parse_with(&input, 64).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Input::from_file("test-input").unwrap();
+
+        assert_eq!(part2(&parse_with(&input, 50).unwrap()), 285);
+        assert_eq!(part2(&parse_with(&input, 76).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let racetrack = parse(&Input::parse("#####\n#S#E#\n#####")).unwrap();
+
+        assert_eq!(part1(&racetrack), Answer::NoSolution(String::from("there is no path from S to E")));
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use aoc_common::parse;
+use aoc_common::{Answer, Coord, Grid, Input, ParseError};
+
+// a code are digits typed on the numeric keypad, followed by 'A'
+pub fn parse(input: &Input) -> Result<Vec<String>, ParseError> {
+    for (index, code) in input.lines().iter().enumerate() {
+        let digits = code.strip_suffix('A')
+            .ok_or_else(|| ParseError::new(code.len() + 1, "'A'", "").at_line(index + 1))?;
+        if digits.is_empty() {
+            return Err(ParseError::new(1, "a digit", "A").at_line(index + 1));
+        }
+        if let Some(column) = digits.find(|c: char| !c.is_ascii_digit()) {
+            let found = &digits[column..column + 1];
+            return Err(ParseError::new(column + 1, "a digit", found).at_line(index + 1));
+        }
+        // too many digits for a usize
+        parse::number::<usize>(digits, 1).map_err(|e| e.at_line(index + 1))?;
+    }
+
+    Ok(input.lines().clone())
+}
+
+// two robots with a directional keypad between the numeric keypad and the human
+pub fn part1(codes: &Vec<String>) -> Answer {
+    complexity(codes, 2)
+}
+
+// with 25 robots in between
+pub fn part2(codes: &Vec<String>) -> Answer {
+    complexity(codes, 25)
+}
+
+fn complexity(codes: &Vec<String>, directional_robots: usize) -> Answer {
+    let mut solver = Solver::new();
+
+    let complexity = codes.iter().try_fold(0usize, |r, code| {
+        let presses = solver.sequence_length(code, directional_robots);
+        // parse checked that the number fits
+        let numeric_part = code.trim_end_matches('A').parse::<usize>().unwrap();
+
+        presses.checked_mul(numeric_part)?.checked_add(r)
+    });
+
+    match complexity {
+        Some(complexity) => Answer::from(complexity),
+        None => Answer::NoSolution(String::from("the complexity is too large for a usize"))
+    }
+}
+
+struct Keypad {
+    layout: Grid<char>,
+    gap: Coord
+}
+
+impl Keypad {
+    pub fn numeric() -> Self {
+        Keypad::from(&["789", "456", "123", " 0A"])
+    }
+
+    pub fn directional() -> Self {
+        Keypad::from(&[" ^A", "<v>"])
+    }
+
+    fn from(rows: &[&str]) -> Self {
+        let rows: Vec<String> = rows.iter().map(|r| r.to_string()).collect();
+        let layout = Grid::parse(&rows, |c| c).unwrap();
+        let gap = layout.find_char(' ').unwrap();
+
+        Keypad { layout, gap }
+    }
+
+    fn position(&self, button: char) -> Coord {
+        match self.layout.find_char(button) {
+            Some(c) => c,
+            None => panic!("Keypad has no button '{}'", button)
+        }
+    }
+
+    // sequences of directional buttons (including the final 'A') which move the arm from
+    // one button to another. Zig-zagging is never shorter, so it's either all horizontal
+    // moves first or all vertical moves first, as long as the arm doesn't cross the gap.
+    pub fn paths(&self, from: char, to: char) -> Vec<String> {
+        let from = self.position(from);
+        let to = self.position(to);
+
+        let horizontal_char = if to.0 < from.0 { '<' } else { '>' };
+        let vertical_char = if to.1 < from.1 { '^' } else { 'v' };
+        let horizontal: String = std::iter::repeat_n(horizontal_char, from.0.abs_diff(to.0) as usize).collect();
+        let vertical: String = std::iter::repeat_n(vertical_char, from.1.abs_diff(to.1) as usize).collect();
+
+        let mut paths: Vec<String> = vec![];
+        if (to.0, from.1) != self.gap {
+            paths.push(format!("{}{}A", horizontal, vertical));
+        }
+        if (from.0, to.1) != self.gap {
+            paths.push(format!("{}{}A", vertical, horizontal));
+        }
+        paths.dedup();
+
+        paths
+    }
+}
+
+struct Solver {
+    numeric: Keypad,
+    directional: Keypad,
+    // (from, to, depth) -> button presses of the human
+    cache: HashMap<(char, char, usize), usize>
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Solver { numeric: Keypad::numeric(), directional: Keypad::directional(), cache: HashMap::new() }
+    }
+
+    // number of buttons the human has to press so that the robot at the numeric keypad types `code`
+    pub fn sequence_length(&mut self, code: &str, robots: usize) -> usize {
+        let mut from = 'A';
+        code.chars().fold(0, |r, to| {
+            let paths = self.numeric.paths(from, to);
+            from = to;
+
+            // the numeric paths are typed on the keypad of the first directional robot,
+            // which has the keypads of the other robots and the human in front of it
+            r + paths.iter().map(|p| self.path_length(p, robots)).min().unwrap()
+        })
+    }
+
+    // presses needed to type `path` on a directional keypad with `depth` directional keypads
+    // in front of it (depth 0 is the keypad of the human)
+    fn path_length(&mut self, path: &str, depth: usize) -> usize {
+        if depth == 0 {
+            return path.len();
+        }
+
+        let mut from = 'A';
+        path.chars().fold(0, |r, to| {
+            let presses = self.move_length(from, to, depth);
+            from = to;
+
+            r + presses
+        })
+    }
+
+    fn move_length(&mut self, from: char, to: char, depth: usize) -> usize {
+        if let Some(presses) = self.cache.get(&(from, to, depth)) {
+            return *presses;
+        }
+
+        let paths = self.directional.paths(from, to);
+        let presses = paths.iter().map(|p| self.path_length(p, depth - 1)).min().unwrap();
+        self.cache.insert((from, to, depth), presses);
+
+        presses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 126384);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 154115708116294);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse(&Input::parse("029A
+A")).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(parse(&Input::parse("02xA")).is_err());
+        let error = parse(&Input::parse("029A\n99999999999999999999A")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_complexity_overflow() {
+        let codes = parse(&Input::parse("9999999999999999999A")).unwrap();
+
+        assert_eq!(part2(&codes), Answer::NoSolution(String::from("the complexity is too large for a usize")));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Vec<Buyer>, ParseError> {
+    input.parse_lines::<Buyer>()
+}
+
+pub fn part1(buyers: &Vec<Buyer>) -> Answer {
+    Answer::from(buyers.iter().fold(0, |r, b| {
+        r + b.compute(2000)
+    }))
+}
+
+pub fn part2(buyers: &Vec<Buyer>) -> Answer {
+    match best_sequence(buyers, 2000) {
+        Some((_, total)) => Answer::from(total),
+        None => Answer::NoSolution(String::from("there are no buyers"))
+    }
+}
+
+// each buyer sells at the first occurrence of the sequence of four price changes. Sum up
+// the prices of all buyers per sequence and return the sequence with the most bananas.
+// None without any sequence of four changes, e.g. without buyers.
+fn best_sequence(buyers: &[Buyer], rounds: usize) -> Option<([i64; 4], u64)> {
+    let mut totals: HashMap<[i64; 4], u64> = HashMap::new();
+
+    buyers.iter().for_each(|b| {
+        let prices = b.prices(rounds);
+        let deltas: Vec<i64> = prices.windows(2).map(|p| p[1] - p[0]).collect();
+
+        let mut seen: HashSet<[i64; 4]> = HashSet::new();
+        deltas.windows(4).enumerate().for_each(|(i, window)| {
+            let sequence = [window[0], window[1], window[2], window[3]];
+            if seen.insert(sequence) {
+                // the price after the fourth change
+                *totals.entry(sequence).or_default() += prices[i + 4] as u64;
+            }
+        });
+    });
+
+    totals.into_iter().max_by_key(|(_, total)| *total)
+}
+
+pub struct Buyer {
+    seed: u64
+}
+
+impl FromStr for Buyer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let seed = parse::number::<u64>(s, 1)?;
+
+        Ok(Buyer { seed })
+    }
+}
+
+impl Buyer {
+    pub fn compute(&self, rounds: usize) -> u64 {
+        self.secrets().nth(rounds).unwrap()
+    }
+
+    // the price is the last digit of the secret. There is one price more than rounds.
+    pub fn prices(&self, rounds: usize) -> Vec<i64> {
+        self.secrets()
+            .take(rounds + 1)
+            .map(|s| (s % 10) as i64)
+            .collect()
+    }
+
+    // all secrets of the buyer, starting with the seed
+    pub fn secrets(&self) -> impl Iterator<Item = u64> {
+        std::iter::successors(Some(self.seed), |secret| Some(next_secret(*secret)))
+    }
+}
+
+fn next_secret(secret: u64) -> u64 {
+    let mut new_secret = secret;
+    new_secret = ((new_secret * 64) ^ new_secret) % 16777216;
+    new_secret = ((new_secret / 32) ^ new_secret) % 16777216;
+    new_secret = ((new_secret * 2048) ^ new_secret) % 16777216;
+
+    new_secret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 37327623);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input-2").unwrap()).unwrap());
+
+        assert_eq!(result, 23);
+    }
+
+    #[test]
+    fn test_best_sequence() {
+        let buyers: Vec<Buyer> = ["1", "2", "3", "2024"].iter().map(|s| s.parse::<Buyer>().unwrap()).collect();
+
+        assert_eq!(best_sequence(&buyers, 2000), Some(([-2, 1, -1, 3], 23)));
+        assert_eq!(best_sequence(&buyers, 3), None);
+        assert_eq!(part2(&vec![]), Answer::NoSolution(String::from("there are no buyers")));
+        assert_eq!("123".parse::<Buyer>().unwrap().prices(9), vec![3, 0, 6, 5, 4, 4, 6, 4, 4, 2]);
+    }
+}
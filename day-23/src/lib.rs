@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<Graph, ParseError> {
+    Graph::try_from(input.lines())
+}
+
+pub fn part1(graph: &Graph) -> Answer {
+    Answer::from(graph.number_of_filtered_3_cliques())
+}
+
+pub fn part2(graph: &Graph) -> Answer {
+    Answer::from(graph.password())
+}
+
+pub struct Graph {
+    // node id -> name of the computer
+    names: Vec<String>,
+    // node id -> ids of all connected nodes
+    edges: Vec<NodeSet>
+}
+
+impl TryFrom<&Vec<String>> for Graph {
+    type Error = ParseError;
+
+    // e.g.: kh-tc
+    fn try_from(lines: &Vec<String>) -> Result<Self, Self::Error> {
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut names: Vec<String> = vec![];
+        let mut pairs: Vec<(usize, usize)> = vec![];
+
+        for (index, line) in lines.iter().enumerate() {
+            let ((c1, c1_column), (c2, c2_column)) = parse::split_once(line, "-", 1)
+                .map_err(|e| e.at_line(index + 1))?;
+            for (name, column) in [(c1, c1_column), (c2, c2_column)] {
+                if name.is_empty() || name.contains('-') {
+                    return Err(ParseError::new(column, "a computer name", name).at_line(index + 1));
+                }
+            }
+
+            let n1 = Graph::node_id(c1, &mut ids, &mut names);
+            let n2 = Graph::node_id(c2, &mut ids, &mut names);
+
+            pairs.push((n1, n2));
+        }
+
+        let mut edges: Vec<NodeSet> = vec![NodeSet::new(names.len()); names.len()];
+        pairs.iter().for_each(|(n1, n2)| {
+            edges[*n1].insert(*n2);
+            edges[*n2].insert(*n1);
+        });
+
+        Ok(Graph { names, edges })
+    }
+}
+
+impl Graph {
+    fn node_id(name: &str, ids: &mut HashMap<String, usize>, names: &mut Vec<String>) -> usize {
+        *ids.entry(String::from(name)).or_insert_with(|| {
+            names.push(String::from(name));
+            names.len() - 1
+        })
+    }
+
+    // each 3-clique is found once from its smallest node id. Only count a
+    // clique if a node starting with "t" is included
+    pub fn number_of_filtered_3_cliques(&self) -> usize {
+        let mut counter = 0;
+        for a in 0..self.names.len() {
+            for b in self.edges[a].iter().filter(|b| *b > a) {
+                let common = self.edges[a].intersection(&self.edges[b]);
+                for c in common.iter().filter(|c| *c > b) {
+                    if [a, b, c].iter().any(|n| self.names[*n].starts_with("t")) {
+                        counter += 1;
+                    }
+                }
+            }
+        }
+
+        counter
+    }
+
+    // names of the largest clique, sorted and joined by commas
+    pub fn password(&self) -> String {
+        let clique = self.maximum_clique();
+        let mut names: Vec<&String> = clique.iter().map(|n| &self.names[n]).collect();
+        names.sort();
+
+        names.iter().map(|n| n.as_str()).collect::<Vec<&str>>().join(",")
+    }
+
+    fn maximum_clique(&self) -> NodeSet {
+        let nodes = self.names.len();
+        let mut all_nodes = NodeSet::new(nodes);
+        (0..nodes).for_each(|n| all_nodes.insert(n));
+
+        let mut best = NodeSet::new(nodes);
+        self.bron_kerbosch(NodeSet::new(nodes), all_nodes, NodeSet::new(nodes), &mut best);
+
+        best
+    }
+
+    // r: current clique, p: candidates which extend r, x: nodes which were already tried
+    fn bron_kerbosch(&self, r: NodeSet, mut p: NodeSet, mut x: NodeSet, best: &mut NodeSet) {
+        if p.is_empty() && x.is_empty() {
+            // r is a maximal clique
+            if r.len() > best.len() {
+                *best = r;
+            }
+            return;
+        }
+
+        // each maximal clique contains the pivot or one of its non-neighbours, so
+        // only those need to be tried. Picking the pivot with most neighbours in p
+        // keeps the number of branches low.
+        let pivot = p.union(&x).iter()
+            .max_by_key(|u| p.intersection(&self.edges[*u]).len())
+            .unwrap();
+
+        for v in p.difference(&self.edges[pivot]).iter() {
+            let mut new_r = r.clone();
+            new_r.insert(v);
+            self.bron_kerbosch(new_r, p.intersection(&self.edges[v]), x.intersection(&self.edges[v]), best);
+
+            p.remove(v);
+            x.insert(v);
+        }
+    }
+}
+
+// set of node ids, stored as bits
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NodeSet {
+    bits: Vec<u64>
+}
+
+impl NodeSet {
+    pub fn new(nodes: usize) -> Self {
+        NodeSet { bits: vec![0; nodes.div_ceil(64)] }
+    }
+
+    pub fn insert(&mut self, n: usize) {
+        self.bits[n / 64] |= 1 << (n % 64);
+    }
+
+    pub fn remove(&mut self, n: usize) {
+        self.bits[n / 64] &= !(1 << (n % 64));
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
+    pub fn union(&self, other: &NodeSet) -> NodeSet {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &NodeSet) -> NodeSet {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &NodeSet) -> NodeSet {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn combine(&self, other: &NodeSet, f: fn(u64, u64) -> u64) -> NodeSet {
+        let bits = self.bits.iter().zip(other.bits.iter()).map(|(a, b)| f(*a, *b)).collect();
+
+        NodeSet { bits }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(i, b)| {
+            (0..64).filter(move |bit| b & (1 << bit) != 0).map(move |bit| i * 64 + bit)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,384 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+use regex::Regex;
+use aoc_common::parse;
+use aoc_common::{Answer, Input, ParseError};
+
+pub fn parse(input: &Input) -> Result<System, ParseError> {
+    System::try_from(input)
+}
+
+pub fn part1(system: &System) -> Answer {
+    let mut system = system.clone();
+    // find out all z-Gates by searching the output gates list
+    let mut z_gates = system.rules.iter().filter(|(gate_name, _)| {
+        gate_name.starts_with("z")
+    }).map(|(gate_name, _)| {
+        gate_name.clone()
+    }).collect::<Vec<String>>();
+
+    system.compute_outputs(&z_gates);
+
+    z_gates.sort_by_key(|b| Reverse(b.clone()));
+
+    let out_string = z_gates.iter().map(|gate| {
+        let gate_value = system.values.get(gate).unwrap();
+        format!("{}", gate_value)
+    }).collect::<Vec<String>>().join("");
+    let result = u64::from_str_radix(out_string.as_str(), 2).unwrap();
+
+    Answer::from(result)
+}
+
+pub fn part2(system: &System) -> Answer {
+    Answer::from(system.swapped_wires().join(","))
+}
+
+#[derive(Debug, Clone)]
+pub struct System {
+    rules: HashMap<String, Gate>,
+    values: HashMap<String, u8>
+}
+
+impl TryFrom<&Input> for System {
+    type Error = ParseError;
+
+    fn try_from(input: &Input) -> Result<Self, Self::Error> {
+        let mut components = input.numbered_sections().into_iter();
+        let (values_line, initial_values) = components.next()
+            .ok_or_else(|| ParseError::new(0, "initial values", "").at_line(1))?;
+        let (rules_line, gate_rules) = components.next()
+            .ok_or_else(|| ParseError::new(0, "gates", "").at_line(input.lines().len() + 1))?;
+
+        // e.g.: x00: 1
+        let mut values = HashMap::new();
+        for (index, iv) in initial_values.iter().enumerate() {
+            let ((gate_name, _), (gate_value, value_column)) = parse::split_once(iv, ": ", 1)
+                .map_err(|e| e.at_line(values_line + index))?;
+            if gate_value != "0" && gate_value != "1" {
+                return Err(ParseError::new(value_column, "'0' or '1'", gate_value).at_line(values_line + index));
+            }
+
+            values.insert(String::from(gate_name), if gate_value == "1" { 1 } else { 0 });
+        }
+
+        // e.g.: x00 AND y00 -> z00
+        let mut rules = HashMap::new();
+        for (index, gr) in gate_rules.iter().enumerate() {
+            let ((gate, _), (result_gate, result_column)) = parse::split_once(gr, " -> ", 1)
+                .map_err(|e| e.at_line(rules_line + index))?;
+            let operation = gate.parse::<Gate>().map_err(|e| e.at_line(rules_line + index))?;
+            if !is_wire(result_gate) {
+                return Err(ParseError::new(result_column, "a wire", result_gate).at_line(rules_line + index));
+            }
+
+            rules.insert(String::from(result_gate), operation);
+        }
+
+        Ok(System { rules, values })
+    }
+}
+
+// three lowercase letters or digits, e.g. x00 or kjc
+fn is_wire(name: &str) -> bool {
+    let re = Regex::new(r"^[a-z0-9]{3}$").unwrap();
+    re.is_match(name)
+}
+
+impl System {
+    pub fn compute_outputs(&mut self, gates: &Vec<String>) {
+        // trigger solve for each z-Gate if no value is present
+        for z_gate in gates {
+            let result = self.compute_value(z_gate);
+            self.values.insert(z_gate.clone(), result);
+        }
+    }
+
+    // the circuit should be a ripple-carry adder. For bit i (with a carry c from bit i - 1):
+    //   x XOR y -> a, a XOR c -> z, x AND y -> b, a AND c -> d, b OR d -> carry.
+    // A wire is swapped if its gate doesn't fit into this pattern.
+    pub fn swapped_wires(&self) -> Vec<String> {
+        let last_z = format!("z{:02}", self.bit_width());
+        let mut swapped: BTreeSet<String> = BTreeSet::new();
+
+        for (out, gate) in &self.rules {
+            let is_first_bit = gate.has_input("x00");
+            let is_input_gate = gate.inputs().iter().all(|g| g.starts_with("x") || g.starts_with("y"));
+
+            let is_wrong = match gate {
+                _ if out.starts_with("z") && *out == last_z => !matches!(gate, Gate::Or(_, _)),
+                Gate::Xor(_, _) if out.starts_with("z") => is_input_gate && !is_first_bit,
+                Gate::Xor(_, _) if !is_input_gate => true, // a XOR c must be a z wire
+                Gate::Xor(_, _) => !is_first_bit && !self.feeds_into(out, |g| matches!(g, Gate::Xor(_, _))),
+                _ if out.starts_with("z") => true, // every other z wire is a XOR
+                Gate::And(_, _) => !is_first_bit && !self.feeds_into(out, |g| matches!(g, Gate::Or(_, _))),
+                Gate::Or(_, _) => false
+            };
+
+            if is_wrong {
+                swapped.insert(out.clone());
+            }
+        }
+
+        swapped.into_iter().collect()
+    }
+
+    // tries all ways to pair up the swapped wires and returns the pairs which turn
+    // the circuit into a working adder. The structural checks of `swapped_wires` don't
+    // tell which wires were swapped with each other, simulating additions does.
+    pub fn repair(&self, wires: &[String]) -> Option<Vec<(String, String)>> {
+        if wires.is_empty() {
+            return if self.is_adder(100) { Some(vec![]) } else { None };
+        }
+
+        let first = &wires[0];
+        for i in 1..wires.len() {
+            let rest: Vec<String> = wires.iter().enumerate()
+                .filter(|(j, _)| *j != 0 && *j != i)
+                .map(|(_, w)| w.clone())
+                .collect();
+
+            let swapped = self.with_swap(first, &wires[i]);
+            if let Some(mut pairs) = swapped.repair(&rest) {
+                pairs.push((first.clone(), wires[i].clone()));
+                return Some(pairs);
+            }
+        }
+
+        None
+    }
+
+    pub fn with_swap(&self, w1: &String, w2: &String) -> System {
+        let mut system = self.clone();
+        let g1 = system.rules.remove(w1).unwrap();
+        let g2 = system.rules.remove(w2).unwrap();
+        system.rules.insert(w1.clone(), g2);
+        system.rules.insert(w2.clone(), g1);
+
+        system
+    }
+
+    // checks the circuit with random additions (and a few edge cases)
+    pub fn is_adder(&self, rounds: usize) -> bool {
+        if !self.is_acyclic() {
+            return false;
+        }
+
+        let width = self.bit_width();
+        let mask = (1u64 << width) - 1;
+        let mut random = 0x2545f4914f6cdd1du64;
+        let mut next_random = || {
+            // xorshift
+            random ^= random << 13;
+            random ^= random >> 7;
+            random ^= random << 17;
+            random & mask
+        };
+
+        let mut additions: Vec<(u64, u64)> = vec![(0, 0), (mask, 1), (mask, mask)];
+        (0..rounds).for_each(|_| additions.push((next_random(), next_random())));
+
+        additions.iter().all(|(x, y)| self.add(*x, *y) == x + y)
+    }
+
+    // sets x and y as inputs and reads the number from the z wires
+    pub fn add(&self, x: u64, y: u64) -> u64 {
+        let mut system = self.clone();
+        for bit in 0..self.bit_width() {
+            system.values.insert(format!("x{:02}", bit), ((x >> bit) & 1) as u8);
+            system.values.insert(format!("y{:02}", bit), ((y >> bit) & 1) as u8);
+        }
+
+        (0..=self.bit_width()).fold(0, |r, bit| {
+            let z = system.compute_value(&format!("z{:02}", bit));
+            r | ((z as u64) << bit)
+        })
+    }
+
+    fn bit_width(&self) -> usize {
+        self.values.keys().filter(|v| v.starts_with("x")).count()
+    }
+
+    fn feeds_into<F: Fn(&Gate) -> bool>(&self, wire: &String, kind: F) -> bool {
+        self.rules.values().any(|g| kind(g) && g.has_input(wire))
+    }
+
+    // a wrong swap can introduce a loop, compute_value would never finish then
+    fn is_acyclic(&self) -> bool {
+        let mut finished: HashSet<&String> = HashSet::new();
+        let mut in_progress: HashSet<&String> = HashSet::new();
+
+        self.rules.keys().all(|w| self.visit(w, &mut in_progress, &mut finished))
+    }
+
+    fn visit<'a>(&'a self, wire: &'a String, in_progress: &mut HashSet<&'a String>, finished: &mut HashSet<&'a String>) -> bool {
+        if finished.contains(wire) {
+            return true;
+        }
+        if !in_progress.insert(wire) {
+            return false;
+        }
+
+        if let Some(gate) = self.rules.get(wire) {
+            for input in gate.inputs() {
+                if !self.visit(input, in_progress, finished) {
+                    return false;
+                }
+            }
+        }
+
+        in_progress.remove(wire);
+        finished.insert(wire);
+
+        true
+    }
+
+    fn compute_value(&self, for_gate: &String) -> u8 {
+        if self.values.contains_key(for_gate) {
+            return *self.values.get(for_gate).unwrap();
+        }
+
+        let gate = self.rules.get(for_gate).unwrap();
+        let result = match gate {
+            Gate::And(g1, g2) => {
+                let g1_value = self.compute_value(g1);
+                let g2_value = self.compute_value(g2);
+
+                g1_value & g2_value
+            },
+            Gate::Or(g1, g2) => {
+                let g1_value = self.compute_value(g1);
+                let g2_value = self.compute_value(g2);
+
+                g1_value | g2_value
+            },
+            Gate::Xor(g1, g2) => {
+                let g1_value = self.compute_value(g1);
+                let g2_value = self.compute_value(g2);
+
+                g1_value ^ g2_value
+            }
+        };
+
+        result
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Gate {
+    And(String, String),
+    Or(String, String),
+    Xor(String, String)
+}
+
+// e.g.: x00 AND y00
+impl FromStr for Gate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split(' ').collect();
+        if tokens.len() != 3 {
+            return Err(ParseError::new(1, "'<wire> <AND|OR|XOR> <wire>'", s));
+        }
+
+        let operation_column = tokens[0].len() + 2;
+        let g2_column = operation_column + tokens[1].len() + 1;
+        for (wire, column) in [(tokens[0], 1), (tokens[2], g2_column)] {
+            if !is_wire(wire) {
+                return Err(ParseError::new(column, "a wire", wire));
+            }
+        }
+
+        let g1 = String::from(tokens[0]);
+        let g2 = String::from(tokens[2]);
+        match tokens[1] {
+            "AND" => Ok(Gate::And(g1, g2)),
+            "OR" => Ok(Gate::Or(g1, g2)),
+            "XOR" => Ok(Gate::Xor(g1, g2)),
+            operation => Err(ParseError::new(operation_column, "'AND', 'OR' or 'XOR'", operation))
+        }
+    }
+}
+
+impl Gate {
+    pub fn inputs(&self) -> [&String; 2] {
+        match self {
+            Gate::And(g1, g2) | Gate::Or(g1, g2) | Gate::Xor(g1, g2) => [g1, g2]
+        }
+    }
+
+    pub fn has_input(&self, wire: &str) -> bool {
+        self.inputs().iter().any(|g| *g == wire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ripple-carry adder over `bits` bits, with the given output wires swapped
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> Input {
+        let mut lines: Vec<String> = vec![];
+        for bit in 0..bits {
+            lines.push(format!("x{:02}: 0", bit));
+            lines.push(format!("y{:02}: 0", bit));
+        }
+        lines.push(String::new());
+
+        let mut gates: Vec<(String, String)> = vec![];
+        gates.push((String::from("x00 XOR y00"), String::from("z00")));
+        gates.push((String::from("x00 AND y00"), String::from("c00")));
+        for bit in 1..bits {
+            let carry_in = format!("c{:02}", bit - 1);
+            let carry_out = if bit == bits - 1 { format!("z{:02}", bits) } else { format!("c{:02}", bit) };
+            gates.push((format!("x{:02} XOR y{:02}", bit, bit), format!("a{:02}", bit)));
+            gates.push((format!("x{:02} AND y{:02}", bit, bit), format!("b{:02}", bit)));
+            gates.push((format!("a{:02} XOR {}", bit, carry_in), format!("z{:02}", bit)));
+            gates.push((format!("a{:02} AND {}", bit, carry_in), format!("d{:02}", bit)));
+            gates.push((format!("b{:02} OR d{:02}", bit, bit), carry_out));
+        }
+
+        for (gate, out) in gates {
+            let out = swaps.iter().fold(out, |out, (w1, w2)| {
+                if out == *w1 { w2.to_string() } else if out == *w2 { w1.to_string() } else { out }
+            });
+            lines.push(format!("{} -> {}", gate, out));
+        }
+
+        Input::parse(&lines.join("\n"))
+    }
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
+
+        assert_eq!(result, 2024);
+    }
+
+    #[test]
+    fn test_part2() {
+        let swaps = [("a01", "b01"), ("z02", "c02"), ("z03", "d03"), ("b04", "z04")];
+        let result = part2(&parse(&adder(6, &swaps)).unwrap());
+
+        assert_eq!(result, "a01,b01,b04,c02,d03,z02,z03,z04");
+    }
+
+    #[test]
+    fn test_repair() {
+        let system = System::try_from(&adder(6, &[])).unwrap();
+        assert!(system.swapped_wires().is_empty());
+        assert!(system.is_adder(100));
+        assert_eq!(system.add(21, 42), 63);
+
+        let broken = System::try_from(&adder(6, &[("a01", "b01"), ("z03", "d03")])).unwrap();
+        assert!(!broken.is_adder(100));
+
+        let wires = broken.swapped_wires();
+        let pairs = broken.repair(&wires).unwrap();
+        assert_eq!(pairs.len(), 2);
+
+        let repaired = pairs.iter().fold(broken, |s, (w1, w2)| s.with_swap(w1, w2));
+        assert!(repaired.is_adder(100));
+    }
+}
@@ -1,61 +1,43 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 use itertools::Itertools;
+use aoc_common::{Answer, Input, ParseError};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Not enough command line arguments");
-    }
-    
-    let input = &args[1];
-    let lines = read_file(input);
-    let result = process(&lines);
-    
-    println!("Result is {}", result);
-}
-
-pub fn read_file(file_name: &String) -> Vec<String> {
-    let lines = fs::read_to_string(file_name)
-        .expect("Could not read file");
-
-    let lines: Vec<String> = lines
-        .trim()
-        .split('\n')
-        .map(String::from)
-        .collect();
-    
-    lines
-}
-
-pub fn process(lines: &Vec<String>) -> usize {
-    let components = lines.split(|l| l.len() == 0);
+pub fn parse(input: &Input) -> Result<Schematics, ParseError> {
+    let components = input.numbered_sections();
 
     let mut locks: Vec<Lock> = vec![];
     let mut keys: Vec<Key> = vec![];
 
-    for component in components {
+    for (first_line, component) in components {
         // it's a lock
-        if component.get(0).unwrap().starts_with("#") {
-            let l = Lock::from(component);
+        if component[0].starts_with("#") {
+            let l = Lock::try_from(component).map_err(|e| e.offset(first_line))?;
             locks.push(l);
         } else { // it's a key
-            let k = Key::from(component);
+            let k = Key::try_from(component).map_err(|e| e.offset(first_line))?;
             keys.push(k);
         }
     }
 
-    let occurrences: HashMap<(u8, u8, u8, u8, u8), u64> = count_occurrences(&locks);
+    Ok(Schematics { locks, keys })
+}
+
+pub fn part1(schematics: &Schematics) -> Answer {
+    let occurrences: HashMap<(u8, u8, u8, u8, u8), u64> = count_occurrences(&schematics.locks);
 
     let mut matching_keys = 0;
-    for key in &keys {
+    for key in &schematics.keys {
         let inverse_pins = (key.pins[0], key.pins[1], key.pins[2], key.pins[3], key.pins[4]);
         let matching_locks = occurrences.get(&inverse_pins).unwrap_or(&0);
         matching_keys += matching_locks;
     }
 
-    matching_keys as usize
+    Answer::from(matching_keys)
+}
+
+pub struct Schematics {
+    locks: Vec<Lock>,
+    keys: Vec<Key>
 }
 
 fn count_occurrences(locks: &Vec<Lock>) -> HashMap<(u8, u8, u8, u8, u8), u64> {
@@ -102,25 +84,45 @@ pub fn extract_components(r: (u8, u8, u8, u8, u8), l: &String) -> (u8, u8, u8, u
     (r.0 + row.0, r.1 + row.1, r.2 + row.2, r.3 + row.3, r.4 + row.4)
 }
 
+// locks and keys are 7 rows of 5 columns, each '#' or '.'
+fn check_schematic(lines: &[String]) -> Result<(), ParseError> {
+    if lines.len() != 7 {
+        let found = format!("{} lines", lines.len());
+        return Err(ParseError::new(0, "a lock or a key (7 lines)", &found).at_line(lines.len().min(7) + 1));
+    }
+
+    for (index, line) in lines.iter().enumerate() {
+        if let Some((column, c)) = line.chars().enumerate().find(|(_, c)| *c != '#' && *c != '.') {
+            return Err(ParseError::new(column + 1, "'#' or '.'", &c.to_string()).at_line(index + 1));
+        }
+        if line.len() != 5 {
+            let found = format!("{} columns", line.len());
+            return Err(ParseError::new(line.len().min(5) + 1, "5 columns", &found).at_line(index + 1));
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct Lock {
     pins: [u8; 5]
 }
 
-impl Lock {
-    pub fn from(lines: &[String]) -> Self {
-        if lines.len() != 7 {
-            panic!("Unknown lock format");
-        }
-        if lines.get(0).unwrap() != "#####" {
-            panic!("Input does not start with lock indicator");
+impl TryFrom<&[String]> for Lock {
+    type Error = ParseError;
+
+    fn try_from(lines: &[String]) -> Result<Self, Self::Error> {
+        check_schematic(lines)?;
+        if lines[0] != "#####" {
+            return Err(ParseError::new(1, "'#####'", &lines[0]).at_line(1));
         }
 
         let pins = lines.iter().dropping(1).dropping_back(1).fold((0, 0, 0, 0, 0), |r, l| {
             extract_components(r, l)
         });
 
-        Lock { pins: [pins.0, pins.1, pins.2, pins.3, pins.4] }
+        Ok(Lock { pins: [pins.0, pins.1, pins.2, pins.3, pins.4] })
     }
 }
 
@@ -129,20 +131,20 @@ struct Key {
     pins: [u8; 5]
 }
 
-impl Key {
-    pub fn from(lines: &[String]) -> Self {
-        if lines.len() != 7 {
-            panic!("Unknown key format");
-        }
-        if lines.get(0).unwrap() != "....." {
-            panic!("Input does not start with key indicator");
+impl TryFrom<&[String]> for Key {
+    type Error = ParseError;
+
+    fn try_from(lines: &[String]) -> Result<Self, Self::Error> {
+        check_schematic(lines)?;
+        if lines[0] != "....." {
+            return Err(ParseError::new(1, "'.....'", &lines[0]).at_line(1));
         }
 
         let pins = lines.iter().dropping(1).dropping_back(1).fold((0, 0, 0, 0, 0), |r, l| {
             extract_components(r, l)
         });
 
-        Key { pins: [pins.0, pins.1, pins.2, pins.3, pins.4] }
+        Ok(Key { pins: [pins.0, pins.1, pins.2, pins.3, pins.4] })
     }
 }
 
@@ -151,8 +153,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part() {
-        let result = process(&read_file(&String::from("../test-input")));
+    fn test_part1() {
+        let result = part1(&parse(&Input::from_file("test-input").unwrap()).unwrap());
 
         assert_eq!(result, 3);
     }